@@ -0,0 +1,155 @@
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use common::{Generated, ParseError, Simulation, Solution};
+
+use crate::timing::Timings;
+use crate::Part;
+
+pub struct Answer {
+    pub part: u8,
+    pub value: String,
+    /// the name of the type the solution answered with, e.g. `usize`
+    pub kind: &'static str,
+    pub elapsed: Duration,
+}
+
+pub type Answers = Vec<Answer>;
+
+/// A day's solution ready to be watched, for the days that can be
+pub type Watchable = Option<Box<dyn Simulation>>;
+
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str, Part) -> Result<Answers, ParseError>,
+    pub time: fn(&str, Part, usize) -> Result<Timings, ParseError>,
+    pub generate: fn(u64, usize) -> Option<Generated>,
+    pub simulate: fn(&str, u8) -> Result<Watchable, ParseError>,
+    pub example: &'static str,
+    pub example_answers: [&'static str; 2],
+}
+
+impl Day {
+    /// Where the day's input lives by default, relative to the workspace root
+    pub fn default_input(&self) -> PathBuf {
+        // inputs kept next to the day's sources win over ones fetched into the cache
+        let beside_sources = PathBuf::from(format!("day{}", self.number))
+            .join("src")
+            .join("input");
+        if workspace_root().join(&beside_sources).exists() {
+            beside_sources
+        } else {
+            client::input_path(Path::new(CACHE_DIR), self.number)
+        }
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        workspace_root().join(self.default_input())
+    }
+}
+
+/// Where fetched inputs are cached, relative to the workspace root
+pub const CACHE_DIR: &str = "inputs";
+
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+fn solve<S: Solution>(input: &str, part: Part) -> Result<Answers, ParseError> {
+    let input = S::parse(input)?;
+
+    let mut answers = vec![];
+    if part != Part::Two {
+        let start = Instant::now();
+        let value = S::part1(&input);
+        answers.push(Answer {
+            part: 1,
+            kind: type_name::<S::Part1>(),
+            elapsed: start.elapsed(),
+            value: value.to_string(),
+        });
+    }
+    if part != Part::One {
+        let start = Instant::now();
+        let value = S::part2(&input);
+        answers.push(Answer {
+            part: 2,
+            kind: type_name::<S::Part2>(),
+            elapsed: start.elapsed(),
+            value: value.to_string(),
+        });
+    }
+    Ok(answers)
+}
+
+// just the type's own name, without the path to it
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn time<S: Solution>(input: &str, part: Part, runs: usize) -> Result<Timings, ParseError> {
+    let mut timings = Timings::default();
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(black_box(input))?);
+        timings.parse.push(start.elapsed());
+        drop(parsed);
+    }
+
+    let input = S::parse(input)?;
+    for _ in 0..runs {
+        if part != Part::Two {
+            let start = Instant::now();
+            black_box(S::part1(black_box(&input)));
+            timings.part1.push(start.elapsed());
+        }
+        if part != Part::One {
+            let start = Instant::now();
+            black_box(S::part2(black_box(&input)));
+            timings.part2.push(start.elapsed());
+        }
+    }
+    Ok(timings)
+}
+
+fn simulate<S: Solution>(input: &str, part: u8) -> Result<Watchable, ParseError> {
+    let input = S::parse(input)?;
+    Ok(S::simulate(&input, part))
+}
+
+const fn day<S: Solution>(number: u8) -> Day {
+    Day {
+        number,
+        solve: solve::<S>,
+        time: time::<S>,
+        generate: S::generate,
+        simulate: simulate::<S>,
+        example: S::EXAMPLE,
+        example_answers: S::EXAMPLE_ANSWERS,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day::<day1::Day1>(1),
+    day::<day2::Day2>(2),
+    day::<day3::Day3>(3),
+    day::<day4::Day4>(4),
+    day::<day5::Day5>(5),
+    day::<day6::Day6>(6),
+    day::<day7::Day7>(7),
+    day::<day8::Day8>(8),
+    day::<day9::Day9>(9),
+    day::<day10::Day10>(10),
+    day::<day11::Day11>(11),
+    day::<day12::Day12>(12),
+    day::<day13::Day13>(13),
+];
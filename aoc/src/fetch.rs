@@ -0,0 +1,36 @@
+use std::process::ExitCode;
+
+use client::Client;
+
+use crate::days::DAYS;
+use crate::FetchArgs;
+
+pub fn fetch(args: FetchArgs) -> ExitCode {
+    let client = Client::new(args.session, args.cache);
+
+    let mut failed = false;
+    for day in DAYS
+        .iter()
+        .filter(|d| args.day.is_none_or(|n| d.number == n))
+    {
+        let cached = client.input_path(day.number).exists();
+        match client.fetch_input(day.number) {
+            Ok(_) if cached => println!("day {}: already cached", day.number),
+            Ok(_) => println!(
+                "day {}: saved to {}",
+                day.number,
+                client.input_path(day.number).display()
+            ),
+            Err(e) => {
+                eprintln!("day {}: {}", day.number, e);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
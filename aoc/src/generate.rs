@@ -0,0 +1,56 @@
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use crate::days;
+use crate::{GenerateArgs, Part};
+
+pub fn generate(args: GenerateArgs) -> ExitCode {
+    let Some(day) = days::find(args.day) else {
+        eprintln!("error: there's no solution for day {}", args.day);
+        return ExitCode::FAILURE;
+    };
+    if (day.generate)(args.seed, 1).is_none() {
+        eprintln!("error: day {} has no input generator", args.day);
+        return ExitCode::FAILURE;
+    }
+
+    let Some(count) = args.check else {
+        let generated = (day.generate)(args.seed, args.size).unwrap();
+        // a closed pipe isn't worth a panic
+        let _ = io::stdout().write_all(generated.input.as_bytes());
+        return ExitCode::SUCCESS;
+    };
+
+    let mut failed = 0;
+    for seed in args.seed..args.seed + count {
+        let generated = (day.generate)(seed, args.size).unwrap();
+        let answers = match (day.solve)(&generated.input, Part::All) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("seed {}: {}", seed, e);
+                failed += 1;
+                continue;
+            }
+        };
+        for answer in answers {
+            let expected = &generated.answers[answer.part as usize - 1];
+            if &answer.value != expected {
+                println!(
+                    "seed {} part {}: got {}, expected {}",
+                    seed, answer.part, answer.value, expected
+                );
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "{} inputs of size {} checked, {} wrong answers",
+        count, args.size, failed
+    );
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,330 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::days::DAYS;
+use crate::output::{Format, Record};
+
+mod days;
+mod fetch;
+mod generate;
+mod output;
+mod submit;
+mod timing;
+mod verify;
+mod visualize;
+
+/// Runs the Advent of Code 2022 solutions and prints their answers
+#[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Checks every day's answers against a file of expected answers
+    Verify(VerifyArgs),
+    /// Downloads puzzle inputs into the input cache
+    Fetch(FetchArgs),
+    /// Writes a random input for a day, or checks the solution against random inputs
+    Generate(GenerateArgs),
+    /// Watches a day's solution a step at a time, or records it to replay later
+    Visualize(VisualizeArgs),
+    /// Plays back a recorded visualization
+    Replay(ReplayArgs),
+    /// Submits an answer, working it out from the day's input when it isn't given
+    Submit(SubmitArgs),
+}
+
+#[derive(clap::Args)]
+struct RunArgs {
+    /// The day to run, every day is run when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=13))]
+    day: Option<u8>,
+
+    /// Which part of the puzzle to run
+    #[arg(short, long, value_enum, default_value_t = Part::All)]
+    part: Part,
+
+    /// Read the puzzle input from this file instead, `-` reads from stdin
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Run the worked example from the puzzle statement and check its answers
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+
+    /// How the answers are printed
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Time parsing and each part instead of printing the answers
+    #[arg(short, long)]
+    time: bool,
+
+    /// How many times each stage is run when timing
+    #[arg(long, default_value_t = 10, requires = "time", value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+
+    /// Append the timings to this file as a line of JSON
+    #[arg(long, requires = "time")]
+    time_output: Option<PathBuf>,
+}
+
+#[derive(clap::Args)]
+struct VerifyArgs {
+    /// The file of expected answers
+    #[arg(long, default_value_os_t = days::workspace_root().join("answers.toml"))]
+    answers: PathBuf,
+
+    /// Record the current answers as the new expected answers instead of checking them
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(clap::Args)]
+struct FetchArgs {
+    /// The day to fetch, every day is fetched when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=13))]
+    day: Option<u8>,
+
+    /// The session cookie from a logged in browser
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: String,
+
+    /// Where fetched inputs are kept
+    #[arg(long, default_value_os_t = days::workspace_root().join(days::CACHE_DIR))]
+    cache: PathBuf,
+}
+
+#[derive(clap::Args)]
+struct SubmitArgs {
+    /// The day the answer is for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=13))]
+    day: u8,
+
+    /// The part the answer is for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// The answer to submit, the solution is run to get it when omitted
+    answer: Option<String>,
+
+    /// Read the puzzle input from this file when working out the answer
+    #[arg(short, long, conflicts_with = "answer")]
+    input: Option<PathBuf>,
+
+    /// The session cookie from a logged in browser
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: String,
+
+    /// Where fetched inputs and the submission history are kept
+    #[arg(long, default_value_os_t = days::workspace_root().join(days::CACHE_DIR))]
+    cache: PathBuf,
+}
+
+#[derive(clap::Args)]
+struct GenerateArgs {
+    /// The day to generate an input for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=13))]
+    day: u8,
+
+    /// The same seed always gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Roughly how many items the input has, what an item is depends on the day
+    #[arg(long, default_value_t = 1000)]
+    size: usize,
+
+    /// Check the solution against this many inputs, starting at the seed, instead of printing one
+    #[arg(long, value_name = "INPUTS")]
+    check: Option<u64>,
+}
+
+#[derive(clap::Args)]
+struct VisualizeArgs {
+    /// The day to watch, only some days can be watched
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=13))]
+    day: u8,
+
+    /// Which part of the puzzle to watch
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Read the puzzle input from this file instead, `-` reads from stdin
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Watch the worked example from the puzzle statement
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+
+    /// Write every frame to this file instead of playing them
+    #[arg(long)]
+    record: Option<PathBuf>,
+
+    #[command(flatten)]
+    playback: PlaybackArgs,
+}
+
+#[derive(clap::Args)]
+struct ReplayArgs {
+    /// A file written by `visualize --record`
+    recording: PathBuf,
+
+    #[command(flatten)]
+    playback: PlaybackArgs,
+}
+
+#[derive(clap::Args)]
+struct PlaybackArgs {
+    /// How many frames are shown each second, `+` and `-` change it while playing
+    #[arg(long, default_value_t = 10.0)]
+    fps: f64,
+
+    /// Start paused, space plays and `n` steps a frame at a time
+    #[arg(long)]
+    paused: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    All,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    match args.command {
+        Some(Command::Verify(args)) => verify(args),
+        Some(Command::Fetch(args)) => fetch::fetch(args),
+        Some(Command::Generate(args)) => generate::generate(args),
+        Some(Command::Visualize(args)) => visualize::visualize(args),
+        Some(Command::Replay(args)) => visualize::replay(args),
+        Some(Command::Submit(args)) => submit::submit(args),
+        None => run(args.run),
+    }
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let result = if args.record {
+        verify::record(&args.answers).map(|_| true)
+    } else {
+        verify::verify(&args.answers)
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let days = DAYS
+        .iter()
+        .filter(|d| args.day.is_none_or(|n| d.number == n))
+        .collect::<Vec<_>>();
+
+    let table = args.format == Format::Table;
+    if table && args.time {
+        timing::print_header();
+    } else if table {
+        output::print_header();
+    }
+
+    let mut failed = false;
+    let mut stats = vec![];
+    let mut records = vec![];
+    for day in days {
+        let input = if args.example {
+            day.example.to_string()
+        } else {
+            let path = args.input.clone().unwrap_or_else(|| day.input_path());
+            match common::read_input(&path) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("day {}: {}", day.number, e);
+                    failed = true;
+                    continue;
+                }
+            }
+        };
+
+        if args.time {
+            match (day.time)(&input, args.part, args.runs as usize) {
+                Ok(timings) => {
+                    for s in timings.stats(day.number) {
+                        if table {
+                            timing::print_stats(&s);
+                        }
+                        stats.push(s);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    failed = true;
+                }
+            }
+            continue;
+        }
+
+        match (day.solve)(&input, args.part) {
+            Ok(answers) => {
+                for answer in answers {
+                    let expected = day.example_answers[answer.part as usize - 1];
+                    if args.example && answer.value != expected {
+                        eprintln!(
+                            "day {} part {}: the example should give {}",
+                            day.number,
+                            answer.part,
+                            expected.trim_end()
+                        );
+                        failed = true;
+                    }
+
+                    if table {
+                        output::print_row(day.number, answer.part, &answer.value);
+                    }
+                    records.push(Record::new(day.number, answer));
+                }
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                failed = true;
+            }
+        }
+    }
+
+    if !table && args.time {
+        output::print_json(&stats);
+    } else if !table {
+        output::print_json(&records);
+    }
+
+    if let Some(path) = &args.time_output {
+        if let Err(e) = timing::append_json(path, &stats) {
+            eprintln!("error: {:#}", e);
+            failed = true;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
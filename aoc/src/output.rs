@@ -0,0 +1,55 @@
+use serde::Serialize;
+
+use crate::days::Answer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+/// An answer as it's written out in the JSON format
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub elapsed_ns: u128,
+}
+
+impl Record {
+    pub fn new(day: u8, answer: Answer) -> Record {
+        Record {
+            day,
+            part: answer.part,
+            answer: answer.value,
+            kind: answer.kind,
+            elapsed_ns: answer.elapsed.as_nanos(),
+        }
+    }
+}
+
+pub fn print_header() {
+    println!("{:>3}  {:>4}  Answer", "Day", "Part");
+}
+
+pub fn print_row(day: u8, part: u8, answer: &str) {
+    // multi line answers (like the day 10 CRT) are lined up under the answer column
+    let mut lines = answer.trim_end().lines();
+    println!(
+        "{:>3}  {:>4}  {}",
+        day,
+        part,
+        lines.next().unwrap_or_default()
+    );
+    for line in lines {
+        println!("{:>3}  {:>4}  {}", "", "", line);
+    }
+}
+
+pub fn print_json<T: Serialize>(records: &[T]) {
+    // serializing plain records can't fail
+    println!("{}", serde_json::to_string_pretty(records).unwrap());
+}
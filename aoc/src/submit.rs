@@ -0,0 +1,75 @@
+use std::process::ExitCode;
+
+use anyhow::{anyhow, bail, Context};
+use client::{Client, Outcome};
+
+use crate::days;
+use crate::{Part, SubmitArgs};
+
+pub fn submit(args: SubmitArgs) -> ExitCode {
+    let answer = match &args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => match solve(&args) {
+            Ok(answer) => answer,
+            Err(e) => {
+                eprintln!("error: {:#}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let client = Client::new(args.session, args.cache);
+    let outcome = match client.submit(args.day, args.part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("day {} part {}: {}", args.day, args.part, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let message = match outcome {
+        Outcome::Correct => "correct!".to_string(),
+        Outcome::TooHigh => "wrong, too high".to_string(),
+        Outcome::TooLow => "wrong, too low".to_string(),
+        Outcome::Incorrect => "wrong".to_string(),
+        Outcome::RateLimited(Some(wait)) => {
+            format!("answered too recently, wait {}s", wait.as_secs())
+        }
+        Outcome::RateLimited(None) => "answered too recently".to_string(),
+        Outcome::WrongLevel => "already solved or not unlocked yet".to_string(),
+    };
+    println!(
+        "day {} part {}: {} {}",
+        args.day, args.part, answer, message
+    );
+
+    if outcome == Outcome::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+// runs the solution for the part being submitted
+fn solve(args: &SubmitArgs) -> anyhow::Result<String> {
+    let day =
+        days::find(args.day).ok_or_else(|| anyhow!("there's no solution for day {}", args.day))?;
+    let path = args.input.clone().unwrap_or_else(|| day.input_path());
+    let input = common::read_input(&path)?;
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+
+    let answers = (day.solve)(&input, part).map_err(|e| anyhow!("{}", e))?;
+    let answer = answers
+        .into_iter()
+        .next()
+        .context("the solution gave no answer")?;
+    if answer.value.trim().contains('\n') {
+        bail!(
+            "day {} part {} draws its answer, read it off and pass it in:\n{}",
+            args.day,
+            args.part,
+            answer.value
+        );
+    }
+    Ok(answer.value.trim().to_string())
+}
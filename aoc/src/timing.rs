@@ -0,0 +1,111 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use serde::Serialize;
+
+/// How long each run of a day's stages took
+#[derive(Debug, Default)]
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Stats {
+    pub day: u8,
+    pub stage: &'static str,
+    pub runs: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub p95_ns: u128,
+    pub mean_ns: u128,
+}
+
+impl Stats {
+    fn new(day: u8, stage: &'static str, samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.iter().map(Duration::as_nanos).collect::<Vec<_>>();
+        sorted.sort_unstable();
+        Some(Stats {
+            day,
+            stage,
+            runs: sorted.len(),
+            min_ns: sorted[0],
+            median_ns: percentile(&sorted, 0.5),
+            p95_ns: percentile(&sorted, 0.95),
+            mean_ns: sorted.iter().sum::<u128>() / sorted.len() as u128,
+        })
+    }
+}
+
+impl Timings {
+    pub fn stats(&self, day: u8) -> Vec<Stats> {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+        .into_iter()
+        .filter_map(|(stage, samples)| Stats::new(day, stage, samples))
+        .collect()
+    }
+}
+
+// nearest rank percentile of already sorted samples
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+pub fn print_header() {
+    println!(
+        "{:>3}  {:<5}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Stage", "Runs", "Min", "Median", "p95", "Mean"
+    );
+}
+
+pub fn print_stats(stats: &Stats) {
+    println!(
+        "{:>3}  {:<5}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}",
+        stats.day,
+        stats.stage,
+        stats.runs,
+        format_ns(stats.min_ns),
+        format_ns(stats.median_ns),
+        format_ns(stats.p95_ns),
+        format_ns(stats.mean_ns),
+    );
+}
+
+fn format_ns(ns: u128) -> String {
+    format!("{:.1?}", Duration::from_nanos(ns as u64))
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    timestamp: u64,
+    stats: &'a [Stats],
+}
+
+/// Appends the stats as a single JSON line, so repeated runs build up a history in one file
+pub fn append_json(path: &Path, stats: &[Stats]) -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let line = serde_json::to_string(&Record { timestamp, stats })?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("could not open {}", path.display()))?;
+    writeln!(file, "{}", line).with_context(|| format!("could not write {}", path.display()))
+}
@@ -0,0 +1,145 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+
+use crate::days::{self, DAYS};
+use crate::Part;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct AnswersFile {
+    #[serde(default, rename = "answer")]
+    answers: Vec<Answer>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Answer {
+    day: u8,
+    part: u8,
+    /// the puzzle input, relative to the answers file
+    input: PathBuf,
+    expected: String,
+}
+
+/// Runs every answer in the file, printing each result, returns whether they all matched
+pub fn verify(path: &Path) -> anyhow::Result<bool> {
+    let file = load(path)?;
+    let base = base_dir(path);
+
+    let mut failures = 0;
+    for answer in &file.answers {
+        let label = format!(
+            "day {:>2} part {} ({})",
+            answer.day,
+            answer.part,
+            answer.input.display()
+        );
+        match solve(answer.day, answer.part, &base.join(&answer.input)) {
+            Ok(actual) if actual == answer.expected => println!("{}: ok", label),
+            Ok(actual) => {
+                failures += 1;
+                println!("{}: MISMATCH", label);
+                println!("  expected: {}", answer.expected.trim_end());
+                println!("  actual:   {}", actual.trim_end());
+            }
+            Err(e) => {
+                failures += 1;
+                println!("{}: ERROR", label);
+                println!("  {:#}", e);
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} failed",
+        file.answers.len() - failures,
+        failures
+    );
+    Ok(failures == 0)
+}
+
+/// Reruns every answer in the file and stores the results as the new expected answers, picking
+/// up any day whose default input exists but has no answers yet
+pub fn record(path: &Path) -> anyhow::Result<()> {
+    let mut file = if path.exists() {
+        load(path)?
+    } else {
+        AnswersFile::default()
+    };
+    let base = base_dir(path);
+
+    for day in DAYS {
+        let input = default_input_relative_to(day, base);
+        let recorded = file
+            .answers
+            .iter()
+            .any(|a| a.day == day.number && a.input == input);
+        if !recorded && base.join(&input).exists() {
+            for part in [1, 2] {
+                file.answers.push(Answer {
+                    day: day.number,
+                    part,
+                    input: input.clone(),
+                    expected: String::new(),
+                });
+            }
+        }
+    }
+
+    for answer in &mut file.answers {
+        answer.expected = solve(answer.day, answer.part, &base.join(&answer.input))
+            .with_context(|| format!("day {} part {}", answer.day, answer.part))?;
+    }
+    file.answers
+        .sort_by(|a, b| (a.day, &a.input, a.part).cmp(&(b.day, &b.input, b.part)));
+
+    fs::write(path, toml::to_string_pretty(&file)?)
+        .with_context(|| format!("could not write {}", path.display()))?;
+    println!(
+        "recorded {} answers in {}",
+        file.answers.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+// inputs in the answers file are relative to the directory it's in
+fn base_dir(path: &Path) -> &Path {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    }
+}
+
+fn load(path: &Path) -> anyhow::Result<AnswersFile> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("could not read {}", path.display()))?;
+    toml::from_str(&text).with_context(|| format!("could not parse {}", path.display()))
+}
+
+fn solve(day: u8, part: u8, input: &Path) -> anyhow::Result<String> {
+    let day = days::find(day).ok_or_else(|| anyhow!("there is no day {}", day))?;
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err(anyhow!("there is no part {}", part)),
+    };
+
+    let input = common::read_input(input)?;
+    let answers = (day.solve)(&input, part)?;
+    answers
+        .into_iter()
+        .next()
+        .map(|answer| answer.value)
+        .ok_or_else(|| anyhow!("day {} gave no answer", day.number))
+}
+
+// answers files kept at the workspace root refer to the inputs relatively so they can be shared
+fn default_input_relative_to(day: &days::Day, base: &Path) -> PathBuf {
+    let root = days::workspace_root();
+    match (base.canonicalize(), root.canonicalize()) {
+        (Ok(base), Ok(root)) if base == root => day.default_input(),
+        _ => day.input_path(),
+    }
+}
@@ -0,0 +1,83 @@
+use std::fs;
+use std::io::BufWriter;
+use std::process::ExitCode;
+
+use common::Simulation;
+use visual::{Options, Recording};
+
+use crate::days;
+use crate::{PlaybackArgs, ReplayArgs, VisualizeArgs};
+
+pub fn visualize(args: VisualizeArgs) -> ExitCode {
+    let Some(day) = days::find(args.day) else {
+        eprintln!("error: there's no solution for day {}", args.day);
+        return ExitCode::FAILURE;
+    };
+
+    let input = if args.example {
+        day.example.to_string()
+    } else {
+        let path = args.input.unwrap_or_else(|| day.input_path());
+        match common::read_input(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("day {}: {}", day.number, e);
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+
+    let mut simulation = match (day.simulate)(&input, args.part) {
+        Ok(Some(simulation)) => simulation,
+        Ok(None) => {
+            eprintln!("error: day {} can't be visualized", day.number);
+            return ExitCode::FAILURE;
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match args.record {
+        Some(path) => {
+            let result = fs::File::create(&path)
+                .and_then(|file| visual::record(simulation.as_mut(), &mut BufWriter::new(file)));
+            match result {
+                Ok(frames) => {
+                    eprintln!("recorded {} frames to {}", frames, path.display());
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("error: {}: {}", path.display(), e);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        None => play(simulation.as_mut(), &args.playback),
+    }
+}
+
+pub fn replay(args: ReplayArgs) -> ExitCode {
+    match Recording::load(&args.recording) {
+        Ok(mut recording) => play(&mut recording, &args.playback),
+        Err(e) => {
+            eprintln!("error: {}: {}", args.recording.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn play(simulation: &mut dyn Simulation, playback: &PlaybackArgs) -> ExitCode {
+    let options = Options {
+        frames_per_second: playback.fps,
+        paused: playback.paused,
+    };
+    match visual::play(simulation, options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
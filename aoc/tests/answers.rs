@@ -0,0 +1,24 @@
+use std::path::Path;
+use std::process::Command;
+
+// the answers file refers to personal inputs, so it's only checked when it has been recorded
+#[test]
+fn answers_match_the_recorded_baseline() {
+    let answers = Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml");
+    if !answers.exists() {
+        eprintln!("no answers.toml recorded, skipping");
+        return;
+    }
+
+    let status = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("verify")
+        .arg("--answers")
+        .arg(&answers)
+        .status()
+        .unwrap();
+    assert!(
+        status.success(),
+        "answers differ from {}",
+        answers.display()
+    );
+}
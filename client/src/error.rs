@@ -0,0 +1,45 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// the puzzle hasn't unlocked yet, or doesn't exist
+    NotFound(u8),
+    /// the server didn't accept the session token
+    Unauthorized,
+    RateLimited(Option<Duration>),
+    Status(u16, String),
+    Transport(String),
+    Io(PathBuf, io::Error),
+    History(PathBuf, serde_json::Error),
+    /// the server's reply to a submitted answer wasn't recognised
+    UnknownResponse(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NotFound(day) => write!(f, "day {} isn't available yet", day),
+            ClientError::Unauthorized => write!(f, "the session token was rejected, log in again"),
+            ClientError::RateLimited(Some(wait)) => {
+                write!(f, "rate limited, try again in {}s", wait.as_secs())
+            }
+            ClientError::RateLimited(None) => write!(f, "rate limited, try again later"),
+            ClientError::Status(status, body) => {
+                write!(f, "unexpected response {}: {}", status, body.trim())
+            }
+            ClientError::Transport(e) => write!(f, "request failed: {}", e),
+            ClientError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            ClientError::History(path, e) => {
+                write!(f, "could not read the history in {}: {}", path.display(), e)
+            }
+            ClientError::UnknownResponse(body) => {
+                write!(f, "didn't understand the response: {}", body.trim())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
@@ -0,0 +1,60 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ClientError, Outcome};
+
+/// Every answer the server has judged, so answers are never sent twice
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    pub submissions: Vec<Submission>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// seconds since the unix epoch
+    pub submitted_at: u64,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<History, ClientError> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let text = fs::read_to_string(path).map_err(|e| ClientError::Io(path.to_path_buf(), e))?;
+        serde_json::from_str(&text).map_err(|e| ClientError::History(path.to_path_buf(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ClientError> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| ClientError::Io(dir.to_path_buf(), e))?;
+        }
+        // serializing plain data can't fail
+        let text = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, text).map_err(|e| ClientError::Io(path.to_path_buf(), e))
+    }
+
+    /// The accepted answer for a part, if there is one
+    pub fn correct(&self, day: u8, part: u8) -> Option<&Submission> {
+        self.submissions
+            .iter()
+            .find(|s| s.day == day && s.part == part && s.outcome == Outcome::Correct)
+    }
+
+    /// What the server said last time this exact answer was sent
+    pub fn previous(&self, day: u8, part: u8, answer: &str) -> Option<&Submission> {
+        self.submissions
+            .iter()
+            .rev()
+            .find(|s| s.day == day && s.part == part && s.answer == answer)
+    }
+}
+
+pub fn history_path(cache_dir: &Path) -> PathBuf {
+    cache_dir.join("history.json")
+}
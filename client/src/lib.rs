@@ -0,0 +1,188 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+mod error;
+mod history;
+mod submit;
+
+pub use error::ClientError;
+pub use history::{History, Submission};
+pub use submit::{parse_response, Outcome};
+
+const YEAR: u16 = 2022;
+const USER_AGENT: &str = "github.com/Yantrio/advent-of-code-2022";
+
+/// Talks to the Advent of Code site, keeping downloaded inputs in a cache directory
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+}
+
+impl Client {
+    pub fn new(session: impl Into<String>, cache_dir: impl Into<PathBuf>) -> Client {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: "https://adventofcode.com".to_string(),
+            session: session.into(),
+            cache_dir: cache_dir.into(),
+            min_interval: Duration::from_secs(5),
+        }
+    }
+
+    /// Sends requests to another server, used to test against a local stand-in
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Client {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// The shortest time allowed between two requests to the server
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Client {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Where a day's input is cached, each day gets its own directory
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        input_path(&self.cache_dir, day)
+    }
+
+    /// Gets a day's input from the cache, only downloading it when it hasn't been fetched before
+    pub fn fetch_input(&self, day: u8) -> Result<String, ClientError> {
+        let path = self.input_path(day);
+        if path.exists() {
+            return fs::read_to_string(&path).map_err(|e| ClientError::Io(path, e));
+        }
+
+        let input = self.get(&format!("/{}/day/{}/input", YEAR, day), day)?;
+
+        let dir = path.parent().unwrap_or(&self.cache_dir);
+        fs::create_dir_all(dir).map_err(|e| ClientError::Io(dir.to_path_buf(), e))?;
+        // write then rename so an interrupted download never looks like a cached input
+        let partial = path.with_extension("partial");
+        fs::write(&partial, &input).map_err(|e| ClientError::Io(partial.clone(), e))?;
+        fs::rename(&partial, &path).map_err(|e| ClientError::Io(path, e))?;
+        Ok(input)
+    }
+
+    /// Sends an answer for judging, unless the part is already solved or this answer has been
+    /// judged before, in which case the earlier verdict is given back without asking the server
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Outcome, ClientError> {
+        let history_path = history::history_path(&self.cache_dir);
+        let mut history = History::load(&history_path)?;
+        if let Some(correct) = history.correct(day, part) {
+            return Ok(if correct.answer == answer {
+                Outcome::Correct
+            } else {
+                Outcome::WrongLevel
+            });
+        }
+        if let Some(previous) = history.previous(day, part, answer) {
+            return Ok(previous.outcome);
+        }
+
+        self.throttle()?;
+        let response = self
+            .agent
+            .post(&format!("{}/{}/day/{}/answer", self.base_url, YEAR, day))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        let body = read_response(response, day)?;
+        let outcome = parse_response(&body).ok_or(ClientError::UnknownResponse(body))?;
+
+        if outcome.is_verdict() {
+            history.submissions.push(Submission {
+                day,
+                part,
+                answer: answer.to_string(),
+                outcome,
+                submitted_at: now().as_secs(),
+            });
+            history.save(&history_path)?;
+        }
+        Ok(outcome)
+    }
+
+    /// Everything that has been submitted and judged so far
+    pub fn history(&self) -> Result<History, ClientError> {
+        History::load(&history::history_path(&self.cache_dir))
+    }
+
+    fn get(&self, path: &str, day: u8) -> Result<String, ClientError> {
+        self.throttle()?;
+        let response = self
+            .agent
+            .get(&format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(response, day)
+    }
+
+    // waits until the minimum interval has passed since the last request, which is remembered in
+    // the cache directory so separate runs don't hammer the server either
+    fn throttle(&self) -> Result<(), ClientError> {
+        let marker = self.cache_dir.join(".last-request");
+        let last = fs::read_to_string(&marker)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+        if let Some(last) = last {
+            let wait = (last + self.min_interval).saturating_sub(now());
+            if !wait.is_zero() {
+                thread::sleep(wait);
+            }
+        }
+
+        fs::create_dir_all(&self.cache_dir)
+            .map_err(|e| ClientError::Io(self.cache_dir.clone(), e))?;
+        // rounded up, a marker that's a fraction of a millisecond early would cut the wait short
+        let millis = now().as_nanos().div_ceil(1_000_000);
+        fs::write(&marker, millis.to_string()).map_err(|e| ClientError::Io(marker, e))
+    }
+}
+
+pub fn input_path(cache_dir: &Path, day: u8) -> PathBuf {
+    cache_dir.join(format!("day{}", day)).join("input")
+}
+
+fn read_response(
+    response: Result<ureq::Response, ureq::Error>,
+    day: u8,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string())),
+        Err(ureq::Error::Status(status, response)) => {
+            let retry_after = response
+                .header("Retry-After")
+                .and_then(|s| s.parse().ok())
+                .map(Duration::from_secs);
+            let body = response.into_string().unwrap_or_default();
+            Err(match status {
+                400 | 401 | 403 => ClientError::Unauthorized,
+                404 => ClientError::NotFound(day),
+                429 => ClientError::RateLimited(retry_after),
+                _ => ClientError::Status(status, body),
+            })
+        }
+        Err(e) => Err(ClientError::Transport(e.to_string())),
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// How the server judged a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// an answer was sent too recently, nothing was judged
+    RateLimited(Option<Duration>),
+    /// the part has already been solved, or isn't unlocked yet
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Whether the server actually judged the answer, only these are kept in the history
+    pub fn is_verdict(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+}
+
+/// Works out the outcome from the page the server sends back after an answer is posted
+pub fn parse_response(html: &str) -> Option<Outcome> {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Some(Outcome::Correct)
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Some(Outcome::TooHigh)
+        } else if text.contains("too low") {
+            Some(Outcome::TooLow)
+        } else {
+            Some(Outcome::Incorrect)
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Some(Outcome::RateLimited(wait_time(&text)))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Some(Outcome::WrongLevel)
+    } else {
+        None
+    }
+}
+
+// the message lives in the page's <article>, with any markup stripped out
+fn article_text(html: &str) -> String {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |i| start + i);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+// reads the wait out of "You have 4m 12s left to wait"
+fn wait_time(text: &str) -> Option<Duration> {
+    let end = text.find(" left to wait")?;
+    let start = text[..end].rfind("have ")? + "have ".len();
+
+    let mut seconds = 0;
+    for part in text[start..end].split_whitespace() {
+        let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 60 * 60,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
@@ -0,0 +1,103 @@
+use std::fs;
+use std::time::{Duration, Instant};
+
+use client::{Client, ClientError};
+
+use crate::support::{temp_dir, FakeServer, Response};
+
+mod support;
+
+fn client(server: &FakeServer, cache: &str) -> Client {
+    Client::new("abc123", temp_dir(cache))
+        .with_base_url(&server.url)
+        .with_min_interval(Duration::ZERO)
+}
+
+#[test]
+fn downloads_the_input_with_the_session_cookie() {
+    let server = FakeServer::start(|_| Response::new(200, "1000\n2000\n"));
+    let client = client(&server, "downloads");
+
+    assert_eq!(client.fetch_input(1).unwrap(), "1000\n2000\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2022/day/1/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+    assert!(requests[0].header("user-agent").is_some());
+    assert_eq!(
+        fs::read_to_string(client.cache_dir().join("day1").join("input")).unwrap(),
+        "1000\n2000\n"
+    );
+}
+
+#[test]
+fn cached_inputs_are_never_downloaded_again() {
+    let server = FakeServer::start(|_| Response::new(200, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"));
+    let client = client(&server, "cached");
+
+    client.fetch_input(6).unwrap();
+    assert_eq!(
+        client.fetch_input(6).unwrap(),
+        "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"
+    );
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn each_day_is_cached_separately() {
+    let server = FakeServer::start(|request| Response::new(200, &request.path));
+    let client = client(&server, "separately");
+
+    assert_eq!(client.fetch_input(2).unwrap(), "/2022/day/2/input");
+    assert_eq!(client.fetch_input(3).unwrap(), "/2022/day/3/input");
+    assert!(client.input_path(2).exists());
+    assert!(client.input_path(3).exists());
+}
+
+#[test]
+fn locked_days_are_not_found_and_not_cached() {
+    let server = FakeServer::start(|_| Response::new(404, "Not Found"));
+    let client = client(&server, "locked");
+
+    assert!(matches!(
+        client.fetch_input(25),
+        Err(ClientError::NotFound(25))
+    ));
+    assert!(!client.input_path(25).exists());
+}
+
+#[test]
+fn rejected_sessions_are_unauthorized() {
+    let server =
+        FakeServer::start(|_| Response::new(400, "Puzzle inputs differ by user.  Please log in."));
+    let client = client(&server, "unauthorized");
+
+    assert!(matches!(
+        client.fetch_input(1),
+        Err(ClientError::Unauthorized)
+    ));
+}
+
+#[test]
+fn rate_limits_report_how_long_to_wait() {
+    let server = FakeServer::start(|_| Response::new(429, "").with_header("Retry-After", "60"));
+    let client = client(&server, "rate-limited");
+
+    assert!(matches!(
+        client.fetch_input(1),
+        Err(ClientError::RateLimited(Some(wait))) if wait == Duration::from_secs(60)
+    ));
+}
+
+#[test]
+fn requests_are_spaced_out_by_the_minimum_interval() {
+    let server = FakeServer::start(|_| Response::new(200, "input"));
+    let client = client(&server, "throttled").with_min_interval(Duration::from_millis(300));
+
+    let start = Instant::now();
+    client.fetch_input(1).unwrap();
+    client.fetch_input(2).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
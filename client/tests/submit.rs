@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use client::{parse_response, Client, Outcome};
+
+use crate::support::{temp_dir, FakeServer, Response};
+
+mod support;
+
+const CORRECT: &str = "<main><article><p>That's the right answer!  You are one gold star closer to \
+    collecting enough star fruit. <a href=\"/2022/day/1#part2\">[Continue to Part Two]</a></p></article></main>";
+const TOO_HIGH: &str =
+    "<main><article><p>That's not the right answer; your answer is too high.  If \
+    you're stuck, make sure you're using the full input data. Please wait one minute before trying \
+    again. [<a href=\"/2022/day/1\">Return to Day 1</a>]</p></article></main>";
+const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low.  \
+    Please wait one minute before trying again.</p></article></main>";
+const WRONG: &str = "<main><article><p>That's not the right answer.  If you're stuck, make sure \
+    you're using the full input data.</p></article></main>";
+const TOO_RECENT: &str =
+    "<main><article><p>You gave an answer too recently; you have to wait after \
+    submitting an answer before trying again.  You have 4m 12s left to wait. \
+    [<a href=\"/2022/day/1\">Return to Day 1</a>]</p></article></main>";
+const WRONG_LEVEL: &str =
+    "<main><article><p>You don't seem to be solving the right level.  Did you \
+    already complete it? [<a href=\"/2022/day/1\">Return to Day 1</a>]</p></article></main>";
+
+fn client(server: &FakeServer, cache: &str) -> Client {
+    Client::new("abc123", temp_dir(cache))
+        .with_base_url(&server.url)
+        .with_min_interval(Duration::ZERO)
+}
+
+#[test]
+fn recognises_every_kind_of_response() {
+    assert_eq!(parse_response(CORRECT), Some(Outcome::Correct));
+    assert_eq!(parse_response(TOO_HIGH), Some(Outcome::TooHigh));
+    assert_eq!(parse_response(TOO_LOW), Some(Outcome::TooLow));
+    assert_eq!(parse_response(WRONG), Some(Outcome::Incorrect));
+    assert_eq!(
+        parse_response(TOO_RECENT),
+        Some(Outcome::RateLimited(Some(Duration::from_secs(252))))
+    );
+    assert_eq!(
+        parse_response(&TOO_RECENT.replace("4m 12s", "38s")),
+        Some(Outcome::RateLimited(Some(Duration::from_secs(38))))
+    );
+    assert_eq!(parse_response(WRONG_LEVEL), Some(Outcome::WrongLevel));
+    assert_eq!(parse_response("<html>maintenance</html>"), None);
+}
+
+#[test]
+fn posts_the_level_and_answer() {
+    let server = FakeServer::start(|_| Response::new(200, CORRECT));
+    let client = client(&server, "posts");
+
+    assert_eq!(client.submit(1, 2, "45000").unwrap(), Outcome::Correct);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2022/day/1/answer");
+    assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+    assert_eq!(requests[0].body, "level=2&answer=45000");
+}
+
+#[test]
+fn solved_parts_are_never_submitted_again() {
+    let server = FakeServer::start(|_| Response::new(200, CORRECT));
+    let client = client(&server, "solved");
+
+    client.submit(1, 1, "24000").unwrap();
+    assert_eq!(client.submit(1, 1, "24000").unwrap(), Outcome::Correct);
+    assert_eq!(client.submit(1, 1, "99").unwrap(), Outcome::WrongLevel);
+    assert_eq!(server.requests().len(), 1);
+
+    // other parts are still sent
+    client.submit(1, 2, "45000").unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn wrong_answers_are_remembered() {
+    let server = FakeServer::start(|request| {
+        if request.body.ends_with("answer=100") {
+            Response::new(200, TOO_HIGH)
+        } else {
+            Response::new(200, TOO_LOW)
+        }
+    });
+    let client = client(&server, "wrong");
+
+    assert_eq!(client.submit(2, 1, "100").unwrap(), Outcome::TooHigh);
+    assert_eq!(client.submit(2, 1, "1").unwrap(), Outcome::TooLow);
+    assert_eq!(client.submit(2, 1, "100").unwrap(), Outcome::TooHigh);
+    assert_eq!(server.requests().len(), 2);
+
+    let history = client.history().unwrap();
+    assert_eq!(history.submissions.len(), 2);
+    assert_eq!(history.submissions[0].answer, "100");
+    assert_eq!(history.submissions[0].outcome, Outcome::TooHigh);
+}
+
+#[test]
+fn rate_limited_answers_are_not_recorded() {
+    let server = FakeServer::start(|_| Response::new(200, TOO_RECENT));
+    let client = client(&server, "recent");
+
+    assert_eq!(
+        client.submit(3, 1, "157").unwrap(),
+        Outcome::RateLimited(Some(Duration::from_secs(252)))
+    );
+    assert!(client.history().unwrap().submissions.is_empty());
+
+    // so trying again really does try again
+    client.submit(3, 1, "157").unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn history_survives_a_new_client() {
+    let server = FakeServer::start(|_| Response::new(200, CORRECT));
+    let cache = temp_dir("survives");
+
+    Client::new("abc123", &cache)
+        .with_base_url(&server.url)
+        .with_min_interval(Duration::ZERO)
+        .submit(4, 1, "2")
+        .unwrap();
+    let again = Client::new("abc123", &cache)
+        .with_base_url(&server.url)
+        .with_min_interval(Duration::ZERO);
+    assert_eq!(again.submit(4, 1, "2").unwrap(), Outcome::Correct);
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A problem in a day's puzzle input, pointing at the line and column it was found at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number within the input
+    pub line: usize,
+    /// 1-based character column within the line
+    pub column: usize,
+    /// the full text of the offending line
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error on the first line of `text`, use `offset_lines` to move it to its place in the input
+    pub fn new(day: u8, text: &str, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day,
+            line: 1,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about `token`, which must be a slice of `text`
+    pub fn at(day: u8, text: &str, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(day, text, column_of(text, token), message)
+    }
+
+    /// An error about something missing from the end of `text`
+    pub fn missing(day: u8, text: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(day, text, text.chars().count() + 1, message)
+    }
+
+    pub fn offset_lines(mut self, lines: usize) -> ParseError {
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        writeln!(f, "  {}", self.text)?;
+        write!(f, "  {:>width$}", "^", width = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token` (a slice of the line `text`) into a number or other `FromStr` type
+pub fn parse_token<T>(day: u8, text: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    token.parse().map_err(|e| {
+        ParseError::at(
+            day,
+            text,
+            token,
+            format!("invalid value `{}`: {}", token, e),
+        )
+    })
+}
+
+fn column_of(text: &str, token: &str) -> usize {
+    // work out where the token sits from its position in memory, falling back to the start of the line
+    let start = text.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+    if offset <= text.len() && text.is_char_boundary(offset) {
+        text[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
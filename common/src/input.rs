@@ -0,0 +1,124 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::Solution;
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => {
+                write!(f, "input file {} does not exist", path.display())
+            }
+            InputError::Io(path, e) => write!(f, "could not read {}: {}", path.display(), e),
+            InputError::Stdin(e) => write!(f, "could not read input from stdin: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound(_) => None,
+            InputError::Io(_, e) | InputError::Stdin(e) => Some(e),
+        }
+    }
+}
+
+/// Reads puzzle input from a file, or from stdin when the path is `-`
+pub fn read_input(path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(InputError::Stdin)?;
+        return Ok(input);
+    }
+
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.to_path_buf()),
+        _ => InputError::Io(path.to_path_buf(), e),
+    })
+}
+
+/// Opens puzzle input for reading a bit at a time, from stdin when the path is `-`
+pub fn open_input(path: impl AsRef<Path>) -> Result<Box<dyn BufRead>, InputError> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+
+    match fs::File::open(path) {
+        Ok(file) => Ok(Box::new(BufReader::new(file))),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            Err(InputError::NotFound(path.to_path_buf()))
+        }
+        Err(e) => Err(InputError::Io(path.to_path_buf(), e)),
+    }
+}
+
+/// Reads the input named by the first command line argument, exiting with an error if it can't,
+/// `--example` gives back the day's worked example instead
+pub fn input_from_args(example: &str) -> String {
+    let mut args = std::env::args();
+    let binary = args.next().unwrap_or_default();
+    let Some(path) = args.next() else {
+        eprintln!("usage: {} <input file | - | --example>", binary);
+        std::process::exit(2);
+    };
+    if path == "--example" {
+        return example.to_string();
+    }
+
+    read_input(&path).or_exit()
+}
+
+/// Unwraps a result in a binary's `main`, printing the error and exiting instead of panicking
+pub trait OrExit<T> {
+    fn or_exit(self) -> T;
+}
+
+impl<T, E: fmt::Display> OrExit<T> for Result<T, E> {
+    fn or_exit(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// All a day's binary needs to do: solve the input named on the command line and print both
+/// answers
+pub fn run<S: Solution>() {
+    print_answers::<S>(&input_from_args(S::EXAMPLE));
+}
+
+/// Solves both parts and prints the answers, exiting if the input doesn't parse
+pub fn print_answers<S: Solution>(input: &str) {
+    let parsed = S::parse(input).or_exit();
+
+    print_answer(1, S::part1(&parsed));
+    print_answer(2, S::part2(&parsed));
+}
+
+fn print_answer(part: u8, answer: impl fmt::Display) {
+    let answer = answer.to_string();
+    // drawn answers start on their own line so they line up
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer.trim_end());
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
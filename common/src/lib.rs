@@ -0,0 +1,9 @@
+mod error;
+mod input;
+mod simulation;
+mod solution;
+
+pub use error::{parse_token, ParseError};
+pub use input::{input_from_args, open_input, print_answers, read_input, run, InputError, OrExit};
+pub use simulation::{Frame, Simulation};
+pub use solution::{Generated, Solution};
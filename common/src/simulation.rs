@@ -0,0 +1,15 @@
+/// One picture of a simulation, with a line saying what's going on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub picture: String,
+}
+
+/// A solution that can be watched a step at a time
+pub trait Simulation {
+    /// Draws where the simulation has got to
+    fn frame(&self) -> Frame;
+
+    /// Moves on by one step, returns false once there's nothing left to do
+    fn step(&mut self) -> bool;
+}
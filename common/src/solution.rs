@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+use crate::{ParseError, Simulation};
+
+/// A day's puzzle: parse the input into a model once, then answer both parts from it
+pub trait Solution {
+    type Input<'a>;
+    type Part1: Display;
+    type Part2: Display;
+
+    /// The worked example from the puzzle statement
+    const EXAMPLE: &'static str;
+    /// The answers the puzzle statement gives for the example
+    const EXAMPLE_ANSWERS: [&'static str; 2];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Self::Part1;
+    fn part2(input: &Self::Input<'_>) -> Self::Part2;
+
+    /// Makes a random input with about `size` items from a seed, for the days that have a
+    /// generator. The same seed always gives the same input.
+    fn generate(_seed: u64, _size: usize) -> Option<Generated> {
+        None
+    }
+
+    /// Watches one part being solved, for the days that are step by step simulations
+    fn simulate(_input: &Self::Input<'_>, _part: u8) -> Option<Box<dyn Simulation>> {
+        None
+    }
+}
+
+/// A generated puzzle input and its answers, worked out separately from the solution
+#[derive(Debug, Clone)]
+pub struct Generated {
+    pub input: String,
+    pub answers: [String; 2],
+}
@@ -0,0 +1,30 @@
+use common::Generated;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// `size` elves, each carrying a handful of snacks
+pub fn generate(seed: u64, size: usize) -> Generated {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut input = String::new();
+    let mut totals = vec![];
+    for elf in 0..size.max(1) {
+        if elf > 0 {
+            input.push('\n');
+        }
+        let mut total = 0;
+        for _ in 0..rng.gen_range(1..=15) {
+            let calories = rng.gen_range(1000..=60000);
+            input.push_str(&format!("{}\n", calories));
+            total += calories;
+        }
+        totals.push(total);
+    }
+
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    let top_three: usize = totals.iter().take(3).sum();
+    Generated {
+        input,
+        answers: [totals[0].to_string(), top_three.to_string()],
+    }
+}
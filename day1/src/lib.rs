@@ -0,0 +1,229 @@
+use common::{Generated, ParseError, Solution};
+
+use std::io::BufRead;
+
+mod generate;
+mod parallel;
+mod report;
+mod top;
+mod totals;
+
+pub use parallel::top_k_parallel;
+pub use report::{Bucket, Ranked, Report};
+pub use top::TopK;
+pub use totals::{elf_inventories, elf_totals, Inventories, Inventory, ReadError};
+
+const DAY: u8 = 1;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = u128;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["24000", "45000"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        total_calories_per_person(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        *part1(input.iter())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(input.iter())
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<Generated> {
+        Some(generate::generate(seed, size))
+    }
+}
+
+pub fn total_calories_per_person(input: &str) -> Result<Vec<usize>, ParseError> {
+    let totals: Vec<usize> = elf_totals(input.as_bytes())
+        .map(|total| total.map_err(ReadError::into_parse_error))
+        .collect::<Result<_, _>>()?;
+    if totals.is_empty() {
+        return Err(ParseError::missing(
+            DAY,
+            "",
+            "there are no elves in the input",
+        ));
+    }
+    Ok(totals)
+}
+
+/// The `k` largest totals in any amount of input, largest first, in constant memory
+pub fn top_k<R: BufRead>(reader: R, k: usize) -> Result<Vec<usize>, ReadError> {
+    let mut top = TopK::new(k);
+    for total in elf_totals(reader) {
+        top.push(total?);
+    }
+    Ok(top.into_sorted_vec())
+}
+
+pub fn part1<'a, I>(total_calories_per_person: I) -> &'a usize
+where
+    I: Iterator<Item = &'a usize>,
+{
+    // find the max
+    total_calories_per_person.max().unwrap()
+}
+
+pub fn part2<'a, I>(total_calories_per_person: I) -> u128
+where
+    I: Iterator<Item = &'a usize>,
+{
+    // keep the top 3 as we go rather than sorting everything
+    let mut top = TopK::new(3);
+    top.extend(total_calories_per_person.copied());
+    top.sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::parse(Day1::EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&input).to_string(), Day1::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::parse(Day1::EXAMPLE).unwrap();
+        assert_eq!(Day1::part2(&input).to_string(), Day1::EXAMPLE_ANSWERS[1]);
+    }
+
+    #[test]
+    fn top_k_streams_the_example() {
+        let top = top_k(Day1::EXAMPLE.as_bytes(), 3).unwrap();
+        assert_eq!(top, vec![24000, 11000, 10000]);
+        assert_eq!(top_k(Day1::EXAMPLE.as_bytes(), 10).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn bad_lines_are_reported() {
+        let e = Day1::parse("100\n\n200\nabc\n").unwrap_err();
+        assert_eq!((e.line, e.column), (4, 1));
+        assert!(Day1::parse("\n\n").is_err());
+    }
+
+    #[test]
+    fn untidy_input_is_tolerated() {
+        let input = Day1::parse("1000\r\n 2000 \r\n\r\n3000\r\n\r\n\n\n").unwrap();
+        assert_eq!(input, vec![3000, 3000]);
+    }
+
+    #[test]
+    fn bad_calories_name_the_elf() {
+        let Err(ReadError::Parse { elf, error }) = top_k("1\n\n2\n\n  -7\n".as_bytes(), 1) else {
+            panic!("negative calories should be rejected");
+        };
+        assert_eq!((elf, error.line, error.column), (3, 5, 3));
+        assert!(error.message.contains("negative"));
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let input = format!("1\n\n{}\n1\n", usize::MAX);
+        let Err(ReadError::Overflow { elf, error }) = top_k(input.as_bytes(), 1) else {
+            panic!("the second elf's total should overflow");
+        };
+        assert_eq!((elf, error.line), (2, 4));
+
+        let input = format!("{}0\n", usize::MAX);
+        assert!(matches!(
+            top_k(input.as_bytes(), 1),
+            Err(ReadError::Overflow { elf: 1, .. })
+        ));
+
+        let input = format!("{}\n\n{}\n\n{}\n", usize::MAX, usize::MAX, usize::MAX);
+        let input = Day1::parse(&input).unwrap();
+        assert_eq!(Day1::part2(&input), 3 * usize::MAX as u128);
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        for seed in 0..5 {
+            let input = Day1::generate(seed, 200).unwrap().input;
+            let sequential = top_k(input.as_bytes(), 10).unwrap();
+            for threads in [1, 2, 3, 8, 500] {
+                assert_eq!(
+                    top_k_parallel(input.as_bytes(), 10, threads).unwrap(),
+                    sequential
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parallel_errors_match_sequential() {
+        let mut input = Day1::generate(0, 200).unwrap().input;
+        input.push_str("\n1\n2x\n");
+        let Err(ReadError::Parse { elf, error }) = top_k(input.as_bytes(), 3) else {
+            panic!("the last line should be rejected");
+        };
+        for threads in [2, 7] {
+            let Err(ReadError::Parse {
+                elf: p_elf,
+                error: p_error,
+            }) = top_k_parallel(input.as_bytes(), 3, threads)
+            else {
+                panic!("the last line should be rejected");
+            };
+            assert_eq!((p_elf, p_error), (elf, error.clone()));
+        }
+        let shown = top_k_parallel(input.as_bytes(), 3, 7)
+            .unwrap_err()
+            .to_string();
+        assert!(shown.contains(&format!("elf {}: `2x`", elf)));
+    }
+
+    #[test]
+    fn example_report() {
+        let inventories: Vec<Inventory> = elf_inventories(Day1::EXAMPLE.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let report = Report::new(&inventories, 4).unwrap();
+
+        assert_eq!(report.count, 5);
+        assert_eq!(report.mean, 11000.0);
+        assert_eq!(report.median, 10000.0);
+        assert_eq!(report.percentiles[1], (25, 6000));
+        assert_eq!(report.histogram.iter().map(|b| b.elves).sum::<usize>(), 5);
+        assert_eq!(report.histogram[3].elves, 1);
+
+        let top = report.ranked[0];
+        assert_eq!((top.rank, top.elf, top.items), (1, 4, 3));
+        assert!(Report::new(&[], 4).is_none());
+
+        let huge = format!("0\n\n{}\n", usize::MAX);
+        let inventories: Vec<Inventory> = elf_inventories(huge.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let report = Report::new(&inventories, 10).unwrap();
+        let counts: Vec<usize> = report.histogram.iter().map(|b| b.elves).collect();
+        assert_eq!(counts, vec![1, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(report.histogram[9].to, usize::MAX);
+        assert!(report.to_string().contains(&usize::MAX.to_string()));
+        assert!(Report::new(&inventories, 1)
+            .unwrap()
+            .csv()
+            .contains("0,18446744073709551615,2"));
+    }
+
+    #[test]
+    fn generated_answers() {
+        for seed in 0..10 {
+            let generated = Day1::generate(seed, 200).unwrap();
+            let input = Day1::parse(&generated.input).unwrap();
+            assert_eq!(Day1::part1(&input).to_string(), generated.answers[0]);
+            assert_eq!(Day1::part2(&input).to_string(), generated.answers[1]);
+        }
+    }
+}
@@ -1,35 +1,137 @@
-use itertools::Itertools;
+use std::fs::File;
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use clap::{Parser, ValueEnum};
+use common::{open_input, print_answers, read_input, InputError, OrExit, Solution};
+use day1::{Day1, Report};
+use memmap2::Mmap;
+
+/// Counts the calories the elves are carrying
+#[derive(Parser)]
+struct Args {
+    /// The puzzle input, `-` reads from stdin
+    #[arg(required_unless_present = "example")]
+    input: Option<PathBuf>,
+
+    /// Use the worked example from the puzzle statement instead
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+
+    /// Stream the input and list the K elves carrying the most, however big the input is
+    #[arg(long, value_name = "K")]
+    top: Option<usize>,
+
+    /// Print statistics about every elf's total instead of the answers
+    #[arg(long, value_enum, conflicts_with = "top")]
+    report: Option<Format>,
+
+    /// How many ranges the report's histogram splits the totals into
+    #[arg(long, default_value_t = 10, requires = "report")]
+    buckets: usize,
+
+    /// Map the whole input into memory and split it between threads, for really big inputs
+    #[arg(long, conflicts_with = "report")]
+    parallel: bool,
+
+    /// How many threads to split the input between, one per core by default
+    #[arg(long, requires = "parallel")]
+    threads: Option<usize>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Csv,
+}
 
 fn main() {
-    let input = include_str!("input");
-    let total_calories_per_person = input
-        .split("\n\n")
-        .map(|x| {
-            x.lines()
-                .map(|x| x.parse::<usize>().unwrap())
-                .sum::<usize>()
-        })
-        .collect::<Vec<_>>();
-
-    let most_calories = part1(total_calories_per_person.iter());
-    println!("Part 1: {}", most_calories);
-
-    let sum_of_top_three_calories = part2(total_calories_per_person.iter());
-    println!("Part 2: {}", sum_of_top_three_calories);
-}
-
-fn part1<'a, I>(total_calories_per_person: I) -> &'a usize
-where
-    I: Iterator<Item = &'a usize>,
-{
-    // find the max
-    total_calories_per_person.max().unwrap()
-}
-
-fn part2<'a, I>(total_calories_per_person: I) -> usize
-where
-    I: Iterator<Item = &'a usize>,
-{
-    // sort the vector and return the sum of the top 3
-    total_calories_per_person.sorted().rev().take(3).sum()
+    let args = Args::parse();
+
+    if let Some(format) = args.report {
+        let reader = reader(&args);
+        let inventories: Vec<_> = day1::elf_inventories(reader)
+            .collect::<Result<_, _>>()
+            .or_exit();
+        let Some(report) = Report::new(&inventories, args.buckets) else {
+            eprintln!("error: there are no elves in the input");
+            std::process::exit(1);
+        };
+        match format {
+            Format::Table => print!("{}", report),
+            Format::Csv => print!("{}", report.csv()),
+        }
+        return;
+    }
+
+    if args.parallel {
+        let threads = args
+            .threads
+            .unwrap_or_else(|| thread::available_parallelism().map_or(1, |threads| threads.get()));
+        let input = bytes(&args);
+        let input = input.as_ref().as_ref();
+        match args.top {
+            Some(k) => print_top(&day1::top_k_parallel(input, k, threads).or_exit()),
+            None => {
+                // both answers only need the top three
+                let top = day1::top_k_parallel(input, 3, threads).or_exit();
+                let Some(most) = top.first() else {
+                    eprintln!("error: there are no elves in the input");
+                    std::process::exit(1);
+                };
+                println!("Part 1: {}", most);
+                println!("Part 2: {}", sum(&top));
+            }
+        }
+        return;
+    }
+
+    let Some(k) = args.top else {
+        let input = match &args.input {
+            Some(path) => read_input(path).or_exit(),
+            None => Day1::EXAMPLE.to_string(),
+        };
+        print_answers::<Day1>(&input);
+        return;
+    };
+
+    print_top(&day1::top_k(reader(&args), k).or_exit());
+}
+
+fn print_top(top: &[usize]) {
+    for (rank, total) in top.iter().enumerate() {
+        println!("{:>4}. {}", rank + 1, total);
+    }
+    println!("Total: {}", sum(top));
+}
+
+fn sum(totals: &[usize]) -> u128 {
+    totals.iter().map(|&total| total as u128).sum()
+}
+
+fn reader(args: &Args) -> Box<dyn BufRead> {
+    match &args.input {
+        Some(path) => open_input(path).or_exit(),
+        None => Box::new(Day1::EXAMPLE.as_bytes()),
+    }
+}
+
+// files are mapped rather than read, stdin has to be read in the usual way
+fn bytes(args: &Args) -> Box<dyn AsRef<[u8]>> {
+    match &args.input {
+        Some(path) if path != Path::new("-") => Box::new(map(path).or_exit()),
+        Some(path) => Box::new(read_input(path).or_exit().into_bytes()),
+        None => Box::new(Day1::EXAMPLE.as_bytes()),
+    }
+}
+
+fn map(path: &Path) -> Result<Mmap, InputError> {
+    let error = |e: io::Error| match e.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.to_path_buf()),
+        _ => InputError::Io(path.to_path_buf(), e),
+    };
+    let file = File::open(path).map_err(error)?;
+    // safety: the file is only read, and isn't expected to change while it's being solved
+    unsafe { Mmap::map(&file) }.map_err(error)
 }
@@ -0,0 +1,85 @@
+use std::thread;
+
+use crate::{elf_inventories, ReadError, TopK};
+
+/// The same as [crate::top_k] over input that's all in memory, but split between `threads`
+/// workers
+pub fn top_k_parallel(input: &[u8], k: usize, threads: usize) -> Result<Vec<usize>, ReadError> {
+    let chunks = chunks(input, threads.max(1));
+
+    let results: Vec<_> = thread::scope(|scope| {
+        let workers: Vec<_> = chunks
+            .iter()
+            .map(|&chunk| scope.spawn(move || top_k_chunk(chunk, k)))
+            .collect();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().expect("a worker thread panicked"))
+            .collect()
+    });
+
+    let mut top = TopK::new(k);
+    let (mut lines, mut elves) = (0, 0);
+    for (chunk, result) in chunks.iter().zip(results) {
+        match result {
+            Ok((chunk_top, chunk_elves)) => {
+                top.extend(chunk_top.into_sorted_vec());
+                lines += chunk.iter().filter(|&&b| b == b'\n').count();
+                elves += chunk_elves;
+            }
+            // the first broken chunk is where reading it all in one go would have stopped too
+            Err(e) => return Err(e.offset(lines, elves)),
+        }
+    }
+    Ok(top.into_sorted_vec())
+}
+
+fn top_k_chunk(chunk: &[u8], k: usize) -> Result<(TopK, usize), ReadError> {
+    let mut top = TopK::new(k);
+    let mut elves = 0;
+    for inventory in elf_inventories(chunk) {
+        top.push(inventory?.calories);
+        elves += 1;
+    }
+    Ok((top, elves))
+}
+
+// roughly equal pieces, each ending just after a blank line so no elf is split between two
+fn chunks(input: &[u8], count: usize) -> Vec<&[u8]> {
+    let target = input.len() / count + 1;
+    let mut chunks = vec![];
+    let mut start = 0;
+    while start < input.len() {
+        let end = blank_line_after(input, start + target);
+        chunks.push(&input[start..end]);
+        start = end;
+    }
+    chunks
+}
+
+// the end of the first blank line starting at or after `from`, or the end of the input
+fn blank_line_after(input: &[u8], from: usize) -> usize {
+    if from >= input.len() {
+        return input.len();
+    }
+    // back up to the start of the line `from` is in
+    let mut line_start = input[..from]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+    while line_start < input.len() {
+        let line_end = input[line_start..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(input.len(), |i| line_start + i + 1);
+        // blank the same way the reader sees it, whitespace and all
+        if input[line_start..line_end]
+            .iter()
+            .all(u8::is_ascii_whitespace)
+        {
+            return line_end;
+        }
+        line_start = line_end;
+    }
+    input.len()
+}
@@ -0,0 +1,184 @@
+use std::cmp::Reverse;
+use std::fmt;
+
+use crate::Inventory;
+
+const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+const BAR_WIDTH: usize = 40;
+
+/// Summary statistics over every elf's calorie total
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub count: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    pub percentiles: Vec<(u8, usize)>,
+    pub histogram: Vec<Bucket>,
+    pub ranked: Vec<Ranked>,
+}
+
+/// How many elves have a total between `from` and `to`, inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub from: usize,
+    pub to: usize,
+    pub elves: usize,
+}
+
+/// An elf's place when sorted by calories, `elf` is where they were in the input, both from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ranked {
+    pub rank: usize,
+    pub elf: usize,
+    pub items: usize,
+    pub calories: usize,
+}
+
+impl Report {
+    /// `None` when there are no elves to report on
+    pub fn new(inventories: &[Inventory], buckets: usize) -> Option<Report> {
+        if inventories.is_empty() {
+            return None;
+        }
+        let count = inventories.len();
+
+        let mut ranked: Vec<Ranked> = inventories
+            .iter()
+            .enumerate()
+            .map(|(i, inventory)| Ranked {
+                rank: 0,
+                elf: i + 1,
+                items: inventory.items,
+                calories: inventory.calories,
+            })
+            .collect();
+        // stable, so tied elves stay in input order
+        ranked.sort_by_key(|elf| Reverse(elf.calories));
+        for (i, elf) in ranked.iter_mut().enumerate() {
+            elf.rank = i + 1;
+        }
+
+        // ascending totals for the order statistics
+        let sorted: Vec<usize> = ranked.iter().rev().map(|elf| elf.calories).collect();
+
+        let mean = sorted.iter().map(|&c| c as f64).sum::<f64>() / count as f64;
+        let median = if count % 2 == 1 {
+            sorted[count / 2] as f64
+        } else {
+            (sorted[count / 2 - 1] as f64 + sorted[count / 2] as f64) / 2.0
+        };
+        let variance = sorted
+            .iter()
+            .map(|&c| (c as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| (p, nearest_rank(&sorted, p)))
+            .collect();
+
+        Some(Report {
+            count,
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+            percentiles,
+            histogram: histogram(&sorted, buckets.max(1)),
+            ranked,
+        })
+    }
+
+    /// The same report as comma separated sections, each with its own header and split by a
+    /// blank line
+    pub fn csv(&self) -> String {
+        let mut out = String::from("statistic,value\n");
+        out += &format!("count,{}\n", self.count);
+        out += &format!("mean,{:.2}\n", self.mean);
+        out += &format!("median,{:.1}\n", self.median);
+        out += &format!("std_dev,{:.2}\n", self.std_dev);
+        for (p, value) in &self.percentiles {
+            out += &format!("p{},{}\n", p, value);
+        }
+
+        out += "\nfrom,to,elves\n";
+        for bucket in &self.histogram {
+            out += &format!("{},{},{}\n", bucket.from, bucket.to, bucket.elves);
+        }
+
+        out += "\nrank,elf,items,calories\n";
+        for elf in &self.ranked {
+            out += &format!("{},{},{},{}\n", elf.rank, elf.elf, elf.items, elf.calories);
+        }
+        out
+    }
+}
+
+// the smallest total that at least p% of elves are at or below
+fn nearest_rank(sorted: &[usize], p: u8) -> usize {
+    let rank = (p as usize * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+fn histogram(sorted: &[usize], buckets: usize) -> Vec<Bucket> {
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    // wide enough that the biggest total lands in the last bucket, worked out in u128 since
+    // totals can go right up to usize::MAX
+    let width = (max - min) as u128 / buckets as u128 + 1;
+    let start = |i: usize| min as u128 + i as u128 * width;
+    let clamp = |bound: u128| bound.min(usize::MAX as u128) as usize;
+
+    let mut histogram: Vec<Bucket> = (0..buckets)
+        .map(|i| Bucket {
+            from: clamp(start(i)),
+            to: clamp(start(i + 1) - 1),
+            elves: 0,
+        })
+        .collect();
+    for &calories in sorted {
+        histogram[((calories - min) as u128 / width) as usize].elves += 1;
+    }
+    histogram
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Elves:    {}", self.count)?;
+        writeln!(f, "Mean:     {:.2}", self.mean)?;
+        writeln!(f, "Median:   {:.1}", self.median)?;
+        writeln!(f, "Std dev:  {:.2}", self.std_dev)?;
+        for (p, value) in &self.percentiles {
+            writeln!(f, "{:<9} {}", format!("p{}:", p), value)?;
+        }
+
+        writeln!(f, "\nHistogram:")?;
+        let tallest = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+        let digits = self.histogram.last().map_or(1, |b| b.to.to_string().len());
+        for bucket in &self.histogram {
+            let bar = (bucket.elves * BAR_WIDTH).div_ceil(tallest.max(1));
+            writeln!(
+                f,
+                "{:>digits$} - {:>digits$} | {:<BAR_WIDTH$} {}",
+                bucket.from,
+                bucket.to,
+                "#".repeat(bar),
+                bucket.elves,
+            )?;
+        }
+
+        writeln!(
+            f,
+            "\n{:>6} {:>6} {:>6} {:>10}",
+            "Rank", "Elf", "Items", "Calories"
+        )?;
+        for elf in &self.ranked {
+            writeln!(
+                f,
+                "{:>6} {:>6} {:>6} {:>10}",
+                elf.rank, elf.elf, elf.items, elf.calories
+            )?;
+        }
+        Ok(())
+    }
+}
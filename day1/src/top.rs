@@ -0,0 +1,78 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Keeps the `k` largest values it has been given, without holding on to any of the others
+#[derive(Debug, Clone)]
+pub struct TopK {
+    k: usize,
+    // a min-heap, so the smallest of the kept values is the one to kick out
+    heap: BinaryHeap<Reverse<usize>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> TopK {
+        // `k` can be far more than there are values, so let the heap grow as they arrive
+        TopK {
+            k,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, value: usize) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(value));
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if value > smallest.0 {
+                *smallest = Reverse(value);
+            }
+        }
+    }
+
+    /// The kept values, largest first
+    pub fn into_sorted_vec(self) -> Vec<usize> {
+        // sorting `Reverse`s ascending puts the largest values first
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|r| r.0)
+            .collect()
+    }
+
+    /// Wide enough that adding up the kept values can't overflow
+    pub fn sum(&self) -> u128 {
+        self.heap.iter().map(|r| r.0 as u128).sum()
+    }
+}
+
+impl Extend<usize> for TopK {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_largest() {
+        let mut top = TopK::new(3);
+        top.extend([5, 1, 9, 3, 7, 9, 2]);
+        assert_eq!(top.sum(), 25);
+        assert_eq!(top.into_sorted_vec(), vec![9, 9, 7]);
+    }
+
+    #[test]
+    fn fewer_values_than_k() {
+        let mut top = TopK::new(5);
+        top.extend([4, 8]);
+        assert_eq!(top.into_sorted_vec(), vec![8, 4]);
+        assert!(TopK::new(0).into_sorted_vec().is_empty());
+
+        let mut huge = TopK::new(usize::MAX);
+        huge.extend([1, 2]);
+        assert_eq!(huge.into_sorted_vec(), vec![2, 1]);
+    }
+}
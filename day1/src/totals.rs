@@ -0,0 +1,174 @@
+use std::fmt;
+use std::io::{self, BufRead};
+use std::num::IntErrorKind;
+
+use common::ParseError;
+
+use crate::DAY;
+
+/// What one elf is carrying
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Inventory {
+    pub items: usize,
+    pub calories: usize,
+}
+
+/// Reads each elf's inventory in turn, only ever holding one line of the input
+pub struct Inventories<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    elves: usize,
+}
+
+/// Why the inventories couldn't be read, `elf` counts from 1 like the lines in `error` do and
+/// is only added to the message when the error is shown, so it can be moved about
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    /// a line that isn't a calorie count
+    Parse {
+        elf: usize,
+        error: ParseError,
+    },
+    /// an elf carrying more calories than a `usize` can count
+    Overflow {
+        elf: usize,
+        error: ParseError,
+    },
+}
+
+impl ReadError {
+    /// The error as a problem with the input, for callers reading from memory where io can't fail
+    pub fn into_parse_error(self) -> ParseError {
+        match self {
+            ReadError::Parse { elf, error } | ReadError::Overflow { elf, error } => {
+                naming_elf(elf, error)
+            }
+            ReadError::Io(e) => unreachable!("reading from memory failed: {}", e),
+        }
+    }
+
+    /// Moves an error found partway through the input to its place in the whole, after `lines`
+    /// lines and `elves` elves
+    pub(crate) fn offset(self, lines: usize, elves: usize) -> ReadError {
+        match self {
+            ReadError::Parse { elf, error } => ReadError::Parse {
+                elf: elf + elves,
+                error: error.offset_lines(lines),
+            },
+            ReadError::Overflow { elf, error } => ReadError::Overflow {
+                elf: elf + elves,
+                error: error.offset_lines(lines),
+            },
+            ReadError::Io(e) => ReadError::Io(e),
+        }
+    }
+}
+
+fn naming_elf(elf: usize, error: ParseError) -> ParseError {
+    ParseError {
+        message: format!("elf {}: {}", elf, error.message),
+        ..error
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "could not read the input: {}", e),
+            ReadError::Parse { elf, error } | ReadError::Overflow { elf, error } => {
+                write!(f, "{}", naming_elf(*elf, error.clone()))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+pub fn elf_inventories<R: BufRead>(reader: R) -> Inventories<R> {
+    Inventories {
+        reader,
+        line: String::new(),
+        line_number: 0,
+        elves: 0,
+    }
+}
+
+/// Just the calorie totals from [elf_inventories]
+pub fn elf_totals<R: BufRead>(reader: R) -> impl Iterator<Item = Result<usize, ReadError>> {
+    elf_inventories(reader).map(|inventory| inventory.map(|inventory| inventory.calories))
+}
+
+impl<R: BufRead> Iterator for Inventories<R> {
+    type Item = Result<Inventory, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut inventory = None;
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return inventory.map(Ok),
+                Ok(_) => {}
+                Err(e) => return Some(Err(ReadError::Io(e))),
+            }
+            self.line_number += 1;
+
+            // this also takes care of windows line endings
+            let text = self.line.trim_end_matches(['\n', '\r']);
+            let token = text.trim();
+            if token.is_empty() {
+                // a blank line ends the elf, unless there hasn't been one yet
+                match inventory {
+                    Some(inventory) => {
+                        self.elves += 1;
+                        return Some(Ok(inventory));
+                    }
+                    None => continue,
+                }
+            }
+
+            let elf = self.elves + 1;
+            let error = |message: String| {
+                ParseError::at(DAY, text, token, message).offset_lines(self.line_number - 1)
+            };
+            let calories = match token.parse::<usize>() {
+                Ok(calories) => calories,
+                Err(e) if *e.kind() == IntErrorKind::PosOverflow => {
+                    let message = format!("`{}` is too many calories to count", token);
+                    return Some(Err(ReadError::Overflow {
+                        elf,
+                        error: error(message),
+                    }));
+                }
+                Err(_) => {
+                    let message = if token.starts_with('-') && token[1..].parse::<usize>().is_ok() {
+                        format!("`{}` is a negative calorie count", token)
+                    } else {
+                        format!("`{}` isn't a calorie count", token)
+                    };
+                    return Some(Err(ReadError::Parse {
+                        elf,
+                        error: error(message),
+                    }));
+                }
+            };
+
+            let inventory = inventory.get_or_insert(Inventory {
+                items: 0,
+                calories: 0,
+            });
+            inventory.items += 1;
+            inventory.calories = match inventory.calories.checked_add(calories) {
+                Some(total) => total,
+                None => {
+                    let message = format!("calories add up to more than {} here", usize::MAX);
+                    return Some(Err(ReadError::Overflow {
+                        elf,
+                        error: error(message),
+                    }));
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use common::{parse_token, Frame, ParseError, Simulation, Solution};
+use grid::Grid;
+
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+const DAY: u8 = 10;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = InstructionSet;
+    type Part1 = isize;
+    type Part2 = String;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = [
+        "13140",
+        concat!(
+            "##..##..##..##..##..##..##..##..##..##..\n",
+            "###...###...###...###...###...###...###.\n",
+            "####....####....####....####....####....\n",
+            "#####.....#####.....#####.....#####.....\n",
+            "######......######......######......####\n",
+            "#######.......#######.......#######.....\n",
+        ),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(input)
+    }
+
+    // both parts run the same program, so there's only one thing to watch
+    fn simulate(input: &Self::Input<'_>, _part: u8) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(Beam::new(input.clone())))
+    }
+}
+
+pub fn parse_instructions(input: &str) -> Result<InstructionSet, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: ParseError| e.offset_lines(i)))
+        .collect()
+}
+
+pub fn part1(instructions: &[Instruction]) -> isize {
+    let mut cpu = CPU::new(instructions.to_vec());
+
+    let mut sum = 0;
+    while !cpu.is_end_of_program() {
+        cpu.tick();
+        if let 20 | 60 | 100 | 140 | 180 | 220 = cpu.cycles {
+            sum += cpu.cycles * cpu.register_x;
+        }
+    }
+    sum
+}
+
+pub fn part2(instructions: &[Instruction]) -> String {
+    let mut cpu = CPU::new(instructions.to_vec());
+    let mut crt = CRT::new(40, 6);
+    while !cpu.is_end_of_program() {
+        cpu.tick();
+        crt.tick(&cpu);
+    }
+
+    crt.to_string()
+}
+
+/// The program running a cycle at a time, with the beam drawing the screen as it goes
+pub struct Beam {
+    cpu: CPU,
+    crt: CRT,
+    signal_strength: isize,
+}
+
+impl Beam {
+    pub fn new(instructions: InstructionSet) -> Beam {
+        Beam {
+            cpu: CPU::new(instructions),
+            crt: CRT::new(40, 6),
+            signal_strength: 0,
+        }
+    }
+}
+
+impl Simulation for Beam {
+    fn frame(&self) -> Frame {
+        let screen = &self.crt.screen;
+        let mut picture = Grid::from_rows(
+            screen
+                .rows()
+                .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' })),
+        )
+        .unwrap();
+        if let Some(beam) = picture.get_mut((self.crt.cursor_x, self.crt.cursor_y)) {
+            *beam = '@';
+        }
+        let sprite = (0..screen.width() as isize)
+            .map(|x| {
+                if (x - self.cpu.register_x).abs() <= 1 {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>();
+
+        Frame {
+            caption: format!(
+                "cycle {}, X is {}, signal strength so far {}",
+                self.cpu.cycles, self.cpu.register_x, self.signal_strength
+            ),
+            picture: format!("{}\n{}\n", picture, sprite),
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.cpu.is_end_of_program() {
+            return false;
+        }
+        self.cpu.tick();
+        if let 20 | 60 | 100 | 140 | 180 | 220 = self.cpu.cycles {
+            self.signal_strength += self.cpu.cycles * self.cpu.register_x;
+        }
+        self.crt.tick(&self.cpu);
+        true
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+struct CPU {
+    register_x: isize,
+    cycles: isize,
+    cycles_in_current_instruction: isize,
+    program: InstructionSet,
+    program_counter: usize,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+struct CRT {
+    screen: Grid<bool>,
+
+    cursor_x: usize,
+    cursor_y: usize,
+}
+
+impl CRT {
+    fn new(width: usize, height: usize) -> Self {
+        CRT {
+            cursor_x: 0,
+            cursor_y: 0,
+            screen: Grid::new(width, height, false),
+        }
+    }
+
+    fn tick(&mut self, cpu: &CPU) {
+        // a program running past the last row has nowhere left to draw
+        if self.cursor_y >= self.screen.height() {
+            return;
+        }
+
+        // the sprite is three wide, with the center being at cpu.register_x
+        // if our current cursor_x is within the sprite, we need to draw it
+        let range = cpu.register_x - 1..=cpu.register_x + 1;
+
+        // todo: fix this to be more efficient
+        let x = self.cursor_x as isize;
+        if range.contains(&x) {
+            self.screen[(self.cursor_x, self.cursor_y)] = true;
+        }
+        self.cursor_x += 1;
+        if self.cursor_x >= self.screen.width() {
+            self.cursor_x = 0;
+            self.cursor_y += 1;
+        }
+    }
+}
+
+impl Display for CRT {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pixels = self.screen.render(|&lit| if lit { '#' } else { '.' });
+        write!(f, "{}", pixels)
+    }
+}
+
+impl Debug for CPU {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "C {:?} PC {} X {} CI {:?}",
+            self.cycles, self.program_counter, self.register_x, self.program[self.program_counter]
+        )
+    }
+}
+
+impl CPU {
+    fn new(instructions: InstructionSet) -> CPU {
+        CPU {
+            register_x: 1,
+            cycles: 0,
+            cycles_in_current_instruction: 0,
+            program: instructions,
+            program_counter: 0,
+        }
+    }
+
+    fn tick(&mut self) {
+        let current_instruction = &self.program[self.program_counter];
+        match current_instruction {
+            Instruction::Noop => {
+                // go straight to the next instruction
+                self.next_instruction();
+            }
+            Instruction::AddX(value) => {
+                if self.cycles_in_current_instruction == 2 {
+                    self.register_x += value;
+                    self.next_instruction();
+                }
+            }
+        }
+        self.cycles += 1;
+        self.cycles_in_current_instruction += 1;
+    }
+
+    fn is_end_of_program(&self) -> bool {
+        self.program_counter >= self.program.len()
+    }
+
+    fn next_instruction(&mut self) {
+        self.program_counter += 1;
+        self.cycles_in_current_instruction = 0;
+    }
+}
+
+pub type InstructionSet = Vec<Instruction>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    AddX(isize),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let Some(instruction) = parts.next() else {
+            return Err(ParseError::missing(DAY, s, "expected an instruction"));
+        };
+
+        let parsed = match instruction {
+            "noop" => Instruction::Noop,
+            "addx" => match parts.next() {
+                Some(value) => Instruction::AddX(parse_token(DAY, s, value)?),
+                None => return Err(ParseError::missing(DAY, s, "expected a value to add")),
+            },
+            _ => {
+                return Err(ParseError::at(
+                    DAY,
+                    s,
+                    instruction,
+                    format!("unknown instruction `{}`", instruction),
+                ))
+            }
+        };
+        if let Some(extra) = parts.next() {
+            return Err(ParseError::at(
+                DAY,
+                s,
+                extra,
+                format!("unexpected `{}` after the instruction", extra),
+            ));
+        }
+        Ok(parsed)
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Noop => write!(f, "noop"),
+            Instruction::AddX(value) => write!(f, "addx {}", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day10::parse(Day10::EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input).to_string(), Day10::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10::parse(Day10::EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&input).to_string(), Day10::EXAMPLE_ANSWERS[1]);
+    }
+
+    #[test]
+    fn beam_draws_the_answer() {
+        let input = Day10::parse(Day10::EXAMPLE).unwrap();
+        let mut beam = Beam::new(input);
+        while beam.step() {}
+        let frame = beam.frame();
+        assert!(frame.caption.ends_with(Day10::EXAMPLE_ANSWERS[0]));
+        assert!(frame.picture.starts_with(Day10::EXAMPLE_ANSWERS[1]));
+    }
+
+    #[test]
+    fn trailing_tokens() {
+        let e = Day10::parse("noop\naddx 5 junk\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 8));
+        let e = Day10::parse("noop x\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 6));
+    }
+
+    #[test]
+    fn programs_longer_than_the_screen() {
+        let full = Day10::parse(&"noop\n".repeat(240)).unwrap();
+        let longer = Day10::parse(&"noop\n".repeat(300)).unwrap();
+        assert_eq!(Day10::part2(&longer), Day10::part2(&full));
+    }
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        prop_oneof![
+            Just(Instruction::Noop),
+            any::<isize>().prop_map(Instruction::AddX),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn instruction_round_trip(instruction in instruction()) {
+            prop_assert_eq!(instruction.to_string().parse::<Instruction>().unwrap(), instruction);
+        }
+
+        #[test]
+        fn program_round_trip(program in prop::collection::vec(instruction(), 0..50)) {
+            let text = program.iter().map(|i| format!("{}\n", i)).collect::<String>();
+            prop_assert_eq!(parse_instructions(&text).unwrap(), program);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use common::Generated;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+const MONKEYS: usize = 8;
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Plus(u64),
+    Multiply(u64),
+    Square,
+}
+
+struct Monkey {
+    operation: Operation,
+    test: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+// eight monkeys shaped like the puzzle's, sharing `size` items between them
+pub fn generate(seed: u64, size: usize) -> Generated {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    // some monkeys make an item grow too fast for part 1 to fit in a u64, so roll new ones
+    // until every item can be placed
+    let (monkeys, items, counts) = loop {
+        let monkeys = monkeys(&mut rng);
+        let mut items = vec![vec![]; MONKEYS];
+        let mut counts = vec![0; MONKEYS];
+        let placed = (0..size.max(1)).all(|_| {
+            (0..100).any(|_| {
+                let start = rng.gen_range(0..MONKEYS);
+                let worry = rng.gen_range(50..100);
+                match follow(&monkeys, start, worry, 20, None) {
+                    Some(c) => {
+                        items[start].push(worry);
+                        counts.iter_mut().zip(c).for_each(|(a, b)| *a += b);
+                        true
+                    }
+                    None => false,
+                }
+            })
+        });
+        if placed {
+            break (monkeys, items, counts);
+        }
+    };
+
+    let modulus = monkeys.iter().map(|m| m.test).product::<u64>();
+    let mut long_counts = vec![0; MONKEYS];
+    for (start, worries) in items.iter().enumerate() {
+        for &worry in worries {
+            let c = follow(&monkeys, start, worry, 10000, Some(modulus)).unwrap();
+            long_counts.iter_mut().zip(c).for_each(|(a, b)| *a += b);
+        }
+    }
+
+    let mut input = vec![];
+    for (i, (monkey, worries)) in monkeys.iter().zip(&items).enumerate() {
+        let worries = worries.iter().map(u64::to_string).collect::<Vec<_>>();
+        let operation = match monkey.operation {
+            Operation::Plus(x) => format!("+ {}", x),
+            Operation::Multiply(x) => format!("* {}", x),
+            Operation::Square => "* old".to_string(),
+        };
+        input.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            i,
+            worries.join(", "),
+            operation,
+            monkey.test,
+            monkey.if_true,
+            monkey.if_false
+        ));
+    }
+
+    Generated {
+        input: input.join("\n"),
+        answers: [
+            business(counts).to_string(),
+            business(long_counts).to_string(),
+        ],
+    }
+}
+
+fn monkeys(rng: &mut StdRng) -> Vec<Monkey> {
+    let mut tests = PRIMES.to_vec();
+    tests.shuffle(rng);
+
+    // like the puzzle, one monkey squares, a couple multiply and the rest add
+    let mut operations = vec![Operation::Square];
+    operations.extend((0..2).map(|_| Operation::Multiply(rng.gen_range(2..20))));
+    operations.extend((3..MONKEYS).map(|_| Operation::Plus(rng.gen_range(1..9))));
+    operations.shuffle(rng);
+
+    (0..MONKEYS)
+        .map(|i| {
+            // never throw to yourself, and never to the same monkey either way
+            let mut others = (0..MONKEYS).filter(|&o| o != i).collect::<Vec<_>>();
+            others.shuffle(rng);
+            Monkey {
+                operation: operations[i],
+                test: tests[i],
+                if_true: others[0],
+                if_false: others[1],
+            }
+        })
+        .collect()
+}
+
+// follows a single item around for some rounds, counting how often each monkey inspects it.
+// items never affect each other so they can be followed one at a time
+fn follow(
+    monkeys: &[Monkey],
+    start: usize,
+    worry: u64,
+    rounds: usize,
+    modulus: Option<u64>,
+) -> Option<Vec<usize>> {
+    let mut counts = vec![0; monkeys.len()];
+    let mut monkey = start;
+    let mut worry = worry as u128;
+    let mut round = 0;
+    while round < rounds {
+        let m = &monkeys[monkey];
+        counts[monkey] += 1;
+        worry = match m.operation {
+            Operation::Plus(x) => worry + x as u128,
+            Operation::Multiply(x) => worry * x as u128,
+            Operation::Square => worry.checked_mul(worry)?,
+        };
+        if worry > u64::MAX as u128 {
+            return None;
+        }
+        worry = match modulus {
+            Some(modulus) => worry % modulus as u128,
+            None => worry / 3,
+        };
+
+        let target = if worry.is_multiple_of(m.test as u128) {
+            m.if_true
+        } else {
+            m.if_false
+        };
+        // monkeys take turns in order, so going back to an earlier one waits a round
+        if target < monkey {
+            round += 1;
+        }
+        monkey = target;
+    }
+    Some(counts)
+}
+
+fn business(mut counts: Vec<usize>) -> usize {
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    counts[0] * counts[1]
+}
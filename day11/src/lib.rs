@@ -0,0 +1,490 @@
+use common::{parse_token, Frame, Generated, ParseError, Simulation, Solution};
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+const DAY: u8 = 11;
+
+mod generate;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["10605", "2713310158"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<Generated> {
+        Some(generate::generate(seed, size))
+    }
+
+    fn simulate(input: &Self::Input<'_>, part: u8) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(KeepAway::new(input.clone(), part)))
+    }
+}
+
+pub fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut offset = 0;
+    let mut monkeys = vec![];
+    let mut starting_lines = vec![];
+    for block in input.split("\n\n") {
+        monkeys.push(
+            block
+                .parse::<Monkey>()
+                .map_err(|e| e.offset_lines(offset))?,
+        );
+        starting_lines.push(offset);
+        // skip over the blank line between monkeys too
+        offset += block.lines().count() + 1;
+    }
+
+    // the answer multiplies the two busiest monkeys together
+    if monkeys.len() < 2 {
+        let last = input.lines().count().saturating_sub(1);
+        return Err(ParseError::missing(
+            DAY,
+            input.lines().last().unwrap_or_default(),
+            "expected at least two monkeys",
+        )
+        .offset_lines(last));
+    }
+
+    // make sure every monkey throws to another one that exists
+    for (index, (monkey, &offset)) in monkeys.iter().zip(&starting_lines).enumerate() {
+        for (target, line) in [(monkey.if_true, 4), (monkey.if_false, 5)] {
+            let text = input.lines().nth(offset + line).unwrap_or_default();
+            let message = if target >= monkeys.len() {
+                format!("there is no monkey {} to throw to", target)
+            } else if target == index {
+                // it'd be throwing to itself while its own items are out of its hands
+                format!("monkey {} can't throw to itself", target)
+            } else {
+                continue;
+            };
+            return Err(
+                ParseError::at(DAY, text, last_word(text), message).offset_lines(offset + line)
+            );
+        }
+    }
+
+    // part 2 keeps worry levels down by working modulo every test at once
+    let mut divisor: u64 = 1;
+    for (monkey, &offset) in monkeys.iter().zip(&starting_lines) {
+        let Some(product) = divisor.checked_mul(monkey.test) else {
+            let text = input.lines().nth(offset + 3).unwrap_or_default();
+            return Err(ParseError::at(
+                DAY,
+                text,
+                last_word(text),
+                format!("the divisibility tests multiply to more than {}", u64::MAX),
+            )
+            .offset_lines(offset + 3));
+        };
+        divisor = product;
+    }
+    Ok(monkeys)
+}
+
+// the value at the end of one of a monkey's lines
+fn last_word(line: &str) -> &str {
+    line.split_whitespace().last().unwrap_or_default()
+}
+
+pub fn part1(monkeys: &[Monkey]) -> usize {
+    let mut cloned = monkeys.to_vec();
+    for _ in 0..20 {
+        Monkey::round(&mut cloned, None)
+    }
+    cloned.sort_unstable_by_key(|m| std::cmp::Reverse(m.examined));
+    cloned[0].examined * cloned[1].examined
+}
+
+pub fn part2(monkeys: &[Monkey]) -> usize {
+    let mut cloned = monkeys.to_vec();
+    // parse_monkeys has checked this doesn't overflow
+    let divisor = cloned.iter().map(|m| m.test).product();
+    for _ in 0..10000 {
+        Monkey::round(&mut cloned, Some(divisor))
+    }
+    cloned.sort_unstable_by_key(|m| std::cmp::Reverse(m.examined));
+    cloned[0].examined * cloned[1].examined
+}
+
+/// The monkeys playing keep away a turn at a time, for part 1's 20 rounds or part 2's 10000
+pub struct KeepAway {
+    monkeys: Vec<Monkey>,
+    divisor: Option<u64>,
+    rounds: usize,
+    turns: usize,
+}
+
+impl KeepAway {
+    pub fn new(monkeys: Vec<Monkey>, part: u8) -> KeepAway {
+        let (divisor, rounds) = match part {
+            1 => (None, 20),
+            _ => (Some(monkeys.iter().map(|m| m.test).product()), 10000),
+        };
+        KeepAway {
+            monkeys,
+            divisor,
+            rounds,
+            turns: 0,
+        }
+    }
+}
+
+impl Simulation for KeepAway {
+    fn frame(&self) -> Frame {
+        let caption = match self.turns {
+            0 => "before the first round".to_string(),
+            turns => format!(
+                "round {} of {}, monkey {} has just had its turn",
+                (turns - 1) / self.monkeys.len() + 1,
+                self.rounds,
+                (turns - 1) % self.monkeys.len()
+            ),
+        };
+
+        let mut picture = String::new();
+        for monkey in &self.monkeys {
+            let items = monkey
+                .items
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>();
+            picture.push_str(&format!(
+                "Monkey {} ({:>6} inspected): {}\n",
+                monkey.monkey_id,
+                monkey.examined,
+                items.join(", ")
+            ));
+        }
+        Frame { caption, picture }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.turns == self.rounds * self.monkeys.len() {
+            return false;
+        }
+        let index = self.turns % self.monkeys.len();
+        Monkey::act(&mut self.monkeys, index, self.divisor);
+        self.turns += 1;
+        true
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Monkey {
+    monkey_id: u64,
+    items: Vec<u64>,
+    operation: Operation,
+    test: u64,
+    if_true: usize,
+    if_false: usize,
+
+    examined: usize,
+}
+
+impl Monkey {
+    fn act(monkeys: &mut [Monkey], index: usize, divisor: Option<u64>) {
+        let mut monkey = Monkey::default();
+        // i admit i got this from a guy on reddit, swapping out the memory was my only choice i could think of
+        std::mem::swap(&mut monkey, &mut monkeys[index]);
+        monkey.examined += monkey.items.len();
+        for item in monkey.items.drain(..) {
+            let worry_level = match divisor {
+                Some(d) => monkey.operation.apply(item) % d,
+                None => monkey.operation.apply(item) / 3,
+            };
+
+            monkeys[match worry_level % monkey.test == 0 {
+                true => monkey.if_true,
+                false => monkey.if_false,
+            }]
+            .items
+            .push(worry_level);
+        }
+        std::mem::swap(&mut monkey, &mut monkeys[index]);
+    }
+
+    fn round(monkeys: &mut [Monkey], divisor: Option<u64>) {
+        (0..monkeys.len()).for_each(|index| Self::act(monkeys, index, divisor));
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (line, id) = field(s, 0, "Monkey ")?;
+        let monkey_id = match id.strip_suffix(':') {
+            Some(id) => parse_token(DAY, line, id)?,
+            None => return Err(ParseError::missing(DAY, line, "expected a `:`")),
+        };
+
+        let (line, items) = field(s, 1, "Starting items:")?;
+        let starting_items = items
+            .split(',')
+            .map(str::trim)
+            .filter(|x| !x.is_empty())
+            .map(|x| parse_token(DAY, line, x).map_err(|e| e.offset_lines(1)))
+            .collect::<Result<_, _>>()?;
+
+        let (line, _) = field(s, 2, "Operation:")?;
+        let operation = line.parse().map_err(|e: ParseError| e.offset_lines(2))?;
+
+        let (line, test) = field(s, 3, "Test: divisible by")?;
+        let divisible_test: u64 = parse_token(DAY, line, test).map_err(|e| e.offset_lines(3))?;
+        if divisible_test == 0 {
+            return Err(
+                ParseError::at(DAY, line, test, "can't test for divisibility by 0").offset_lines(3),
+            );
+        }
+
+        let (line, if_true) = field(s, 4, "If true: throw to monkey")?;
+        let if_true = parse_token(DAY, line, if_true).map_err(|e| e.offset_lines(4))?;
+        let (line, if_false) = field(s, 5, "If false: throw to monkey")?;
+        let if_false = parse_token(DAY, line, if_false).map_err(|e| e.offset_lines(5))?;
+
+        Ok(Monkey {
+            monkey_id,
+            items: starting_items,
+            operation,
+
+            test: divisible_test,
+            if_true,
+            if_false,
+            examined: 0,
+        })
+    }
+}
+
+impl Display for Monkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let items = self.items.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+        writeln!(f, "Monkey {}:", self.monkey_id)?;
+        writeln!(f, "  Starting items: {}", items.join(", "))?;
+        writeln!(f, "  Operation: {}", self.operation)?;
+        writeln!(f, "  Test: divisible by {}", self.test)?;
+        writeln!(f, "    If true: throw to monkey {}", self.if_true)?;
+        writeln!(f, "    If false: throw to monkey {}", self.if_false)
+    }
+}
+
+// finds the line at `index` of a monkey's description and returns it with the value after `label`
+fn field<'a>(s: &'a str, index: usize, label: &str) -> Result<(&'a str, &'a str), ParseError> {
+    let Some(line) = s.lines().nth(index) else {
+        return Err(ParseError::new(
+            DAY,
+            "",
+            1,
+            format!("expected a line starting with `{}`", label),
+        )
+        .offset_lines(index));
+    };
+    match line.trim_start().strip_prefix(label) {
+        Some(value) => Ok((line, value.trim())),
+        None => Err(ParseError::at(
+            DAY,
+            line,
+            line.trim_start(),
+            format!("expected a line starting with `{}`", label),
+        )
+        .offset_lines(index)),
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+enum Operation {
+    Plus(u64),
+    Multiply(u64),
+    #[default]
+    Square,
+}
+
+impl Operation {
+    fn apply(&self, item: u64) -> u64 {
+        match self {
+            Operation::Plus(x) => item + x,
+            Operation::Multiply(x) => item * x,
+            Operation::Square => item * item,
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((_, operation)) = s.split_once("new = old ") else {
+            return Err(ParseError::missing(
+                DAY,
+                s,
+                "expected `new = old <operator> <value>`",
+            ));
+        };
+
+        let Some((operator, value)) = operation.split_once(' ') else {
+            return Err(ParseError::missing(DAY, s, "expected `<operator> <value>`"));
+        };
+        match (operator, value) {
+            ("*", "old") => Ok(Operation::Square),
+            ("+", "old") => Err(ParseError::at(DAY, s, value, "only `* old` is supported")),
+            ("+", _) => Ok(Operation::Plus(parse_token(DAY, s, value)?)),
+            ("*", _) => Ok(Operation::Multiply(parse_token(DAY, s, value)?)),
+            _ => Err(ParseError::at(
+                DAY,
+                s,
+                operator,
+                format!("unknown operation `{}`", operator),
+            )),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Plus(x) => write!(f, "new = old + {}", x),
+            Operation::Multiply(x) => write!(f, "new = old * {}", x),
+            Operation::Square => write!(f, "new = old * old"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::parse(Day11::EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input).to_string(), Day11::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day11::parse(Day11::EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&input).to_string(), Day11::EXAMPLE_ANSWERS[1]);
+    }
+
+    #[test]
+    fn one_monkey_is_not_enough() {
+        let first = Day11::EXAMPLE.split("\n\n").next().unwrap();
+        let one = first.replace("throw to monkey 2", "throw to monkey 0");
+        let one = one.replace("throw to monkey 3", "throw to monkey 0");
+        let e = Day11::parse(&one).unwrap_err();
+        assert_eq!(e.line, 6);
+        assert!(e.message.contains("two monkeys"));
+    }
+
+    #[test]
+    fn throwing_to_itself() {
+        let input = Day11::EXAMPLE.replacen(
+            "If false: throw to monkey 3",
+            "If false: throw to monkey 0",
+            1,
+        );
+        let e = Day11::parse(&input).unwrap_err();
+        assert_eq!((e.line, e.column), (6, 31));
+        assert!(e.message.contains("itself"));
+    }
+
+    #[test]
+    fn tests_too_big_to_multiply() {
+        let input = Day11::EXAMPLE.replace("divisible by 19", "divisible by 18446744073709551557");
+        let e = Day11::parse(&input).unwrap_err();
+        // the first monkey's 23 times the second's is already too big
+        assert_eq!((e.line, e.column), (11, 22));
+    }
+
+    #[test]
+    fn keep_away_inspects_like_part1() {
+        let monkeys = Day11::parse(Day11::EXAMPLE).unwrap();
+        let mut keep_away = KeepAway::new(monkeys, 1);
+        while keep_away.step() {}
+        let examined = keep_away
+            .monkeys
+            .iter()
+            .map(|m| m.examined)
+            .collect::<Vec<_>>();
+        assert_eq!(examined, vec![101, 95, 7, 105]);
+    }
+
+    fn operation() -> impl Strategy<Value = Operation> {
+        prop_oneof![
+            any::<u64>().prop_map(Operation::Plus),
+            any::<u64>().prop_map(Operation::Multiply),
+            Just(Operation::Square),
+        ]
+    }
+
+    // a monkey that throws to one of the other `count - 1` monkeys, with a test small enough that
+    // eight of them multiply together without overflowing
+    fn monkey(monkey_id: u64, count: usize) -> impl Strategy<Value = Monkey> {
+        let other = move |target: usize| target + usize::from(target >= monkey_id as usize);
+        (
+            prop::collection::vec(any::<u64>(), 0..10),
+            operation(),
+            1..=255u64,
+            0..count - 1,
+            0..count - 1,
+        )
+            .prop_map(move |(items, operation, test, if_true, if_false)| Monkey {
+                monkey_id,
+                items,
+                operation,
+                test,
+                if_true: other(if_true),
+                if_false: other(if_false),
+                examined: 0,
+            })
+    }
+
+    fn monkeys() -> impl Strategy<Value = Vec<Monkey>> {
+        (2..8usize).prop_flat_map(|count| {
+            (0..count)
+                .map(|id| monkey(id as u64, count))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn monkey_round_trip(monkey in monkey(0, 2)) {
+            prop_assert_eq!(monkey.to_string().parse::<Monkey>().unwrap(), monkey);
+        }
+
+        #[test]
+        fn monkeys_round_trip(monkeys in monkeys()) {
+            let text = monkeys.iter().map(Monkey::to_string).collect::<Vec<_>>().join("\n");
+            prop_assert_eq!(parse_monkeys(&text).unwrap(), monkeys);
+        }
+    }
+
+    #[test]
+    fn generated_answers() {
+        for seed in 0..10 {
+            let generated = Day11::generate(seed, 20).unwrap();
+            let input = Day11::parse(&generated.input).unwrap();
+            assert_eq!(Day11::part1(&input).to_string(), generated.answers[0]);
+            assert_eq!(Day11::part2(&input).to_string(), generated.answers[1]);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use common::Generated;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// rolling hills `size` squares wide, with a ramp along the top and down the right hand side
+// so the top of the hill can always be reached
+pub fn generate(seed: u64, size: usize) -> Generated {
+    let mut rng = StdRng::seed_from_u64(seed);
+    // the ramp needs at least 26 squares to climb from `a` to `z`
+    let width = size.max(14);
+    let height = (size / 2).max(14);
+
+    let mut heights = vec![vec![0u8; width]; height];
+    for y in 0..height {
+        for x in 0..width {
+            let near = match (x, y) {
+                (0, 0) => 0,
+                (0, _) => heights[y - 1][x] as i32,
+                (_, 0) => heights[y][x - 1] as i32,
+                _ => (heights[y - 1][x] as i32 + heights[y][x - 1] as i32) / 2,
+            };
+            heights[y][x] = (near + rng.gen_range(-2..=2)).clamp(0, 25) as u8;
+        }
+    }
+
+    let ramp = (0..width)
+        .map(|x| (x, 0))
+        .chain((1..height).map(|y| (width - 1, y)))
+        .collect::<Vec<_>>();
+    for (i, &(x, y)) in ramp.iter().enumerate() {
+        heights[y][x] = (i * 25 / (ramp.len() - 1)) as u8;
+    }
+    let start = ramp[0];
+    let destination = ramp[ramp.len() - 1];
+
+    let mut input = String::new();
+    for (y, row) in heights.iter().enumerate() {
+        for (x, &h) in row.iter().enumerate() {
+            input.push(match (x, y) {
+                p if p == start => 'S',
+                p if p == destination => 'E',
+                _ => (b'a' + h) as char,
+            });
+        }
+        input.push('\n');
+    }
+
+    let climb = distances(&heights, start, |from, to| to <= from + 1);
+    let descend = distances(&heights, destination, |from, to| from <= to + 1);
+    let part1 = climb[destination.1][destination.0].unwrap();
+    let part2 = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter(|&(x, y)| heights[y][x] == 0)
+        .filter_map(|(x, y)| descend[y][x])
+        .min()
+        .unwrap();
+
+    Generated {
+        input,
+        answers: [part1.to_string(), part2.to_string()],
+    }
+}
+
+// the fewest steps to every square, found by relaxing every square until nothing improves
+fn distances(
+    heights: &[Vec<u8>],
+    from: (usize, usize),
+    can_step: impl Fn(u8, u8) -> bool,
+) -> Vec<Vec<Option<usize>>> {
+    let (width, height) = (heights[0].len(), heights.len());
+    let mut distances = vec![vec![None; width]; height];
+    distances[from.1][from.0] = Some(0);
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for y in 0..height {
+            for x in 0..width {
+                let Some(d) = distances[y][x] else { continue };
+                let steps = [
+                    (x.wrapping_sub(1), y),
+                    (x + 1, y),
+                    (x, y.wrapping_sub(1)),
+                    (x, y + 1),
+                ];
+                for (nx, ny) in steps {
+                    if nx >= width || ny >= height || !can_step(heights[y][x], heights[ny][nx]) {
+                        continue;
+                    }
+                    if distances[ny][nx].is_none_or(|n| n > d + 1) {
+                        distances[ny][nx] = Some(d + 1);
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+    distances
+}
@@ -0,0 +1,178 @@
+use common::{Generated, ParseError, Solution};
+use grid::{Grid, GridError, Position};
+use pathfinding::prelude::bfs;
+
+use std::fmt::{self, Display};
+
+const DAY: u8 = 12;
+
+mod generate;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Heightmap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["31", "29"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_heightmap(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part1(&input.0, &input.1, &input.2)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(&input.0, &input.2)
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<Generated> {
+        Some(generate::generate(seed, size))
+    }
+}
+
+/// The heights, with the start and destination squares already lowered to `a` and raised to `z`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heightmap(pub Grid<u8>, pub Position, pub Position);
+
+impl Display for Heightmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut marked = self.0.clone();
+        marked[self.1] = b'S';
+        marked[self.2] = b'E';
+        write!(f, "{}", marked.render(|&height| height as char))
+    }
+}
+
+pub fn parse_heightmap(input: &str) -> Result<Heightmap, ParseError> {
+    for (y, line) in input.lines().enumerate() {
+        if let Some((x, c)) = line
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !matches!(c, 'a'..='z' | 'S' | 'E'))
+        {
+            return Err(
+                ParseError::new(DAY, line, x + 1, format!("`{}` is not a height", c))
+                    .offset_lines(y),
+            );
+        }
+    }
+
+    let mut grid = Grid::from_rows(input.lines().map(str::bytes)).map_err(|e| match e {
+        GridError::RaggedRow { row, len, expected } => {
+            let line = input.lines().nth(row).unwrap_or_default();
+            ParseError::new(DAY, line, len.min(expected) + 1, e.to_string()).offset_lines(row)
+        }
+    })?;
+
+    let find = |square: u8, name: &str| {
+        grid.positions()
+            .find(|&p| grid[p] == square)
+            .ok_or_else(|| {
+                ParseError::missing(DAY, "", format!("the heightmap has no {} square", name))
+                    .offset_lines(input.lines().count())
+            })
+    };
+    let start = find(b'S', "start")?;
+    let destination = find(b'E', "destination")?;
+
+    grid[start] = b'a';
+    grid[destination] = b'z';
+
+    Ok(Heightmap(grid, start, destination))
+}
+
+/// The fewest steps from the start up to the destination, 0 when there's no way up, which can't
+/// be a real answer as they're different squares
+pub fn part1(grid: &Grid<u8>, start: &Position, destination: &Position) -> usize {
+    bfs(
+        start,
+        |&p| {
+            grid.neighbours(p, false) // dont allow diagonals
+                .filter(move |&q| grid[q] <= grid[p] + 1) // only allow steps of 1
+        },
+        |&p| p == *destination, // stop when we reach the destination
+    )
+    .map_or(0, |path| path.len() - 1)
+}
+
+/// The fewest steps up to the destination from any `a` square, 0 when there's no way up as the
+/// destination itself is a `z`
+pub fn part2(grid: &Grid<u8>, destination: &Position) -> usize {
+    bfs(
+        destination, // work back from the destination
+        |&p| {
+            grid.neighbours(p, false) // no diagonals
+                .filter(move |&q| grid[p] <= grid[q] + 1) // only allow steps of 1 (backwards)
+        },
+        |&p| grid[p] == b'a', // go to any sqare at height 'a'
+    )
+    .map_or(0, |path| path.len() - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day12::parse(Day12::EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input).to_string(), Day12::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12::parse(Day12::EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input).to_string(), Day12::EXAMPLE_ANSWERS[1]);
+    }
+
+    #[test]
+    fn no_way_up() {
+        let input = Day12::parse("SzE\n").unwrap();
+        assert_eq!(Day12::part1(&input), 0);
+        assert_eq!(Day12::part2(&input), 0);
+    }
+
+    fn heightmap() -> impl Strategy<Value = Heightmap> {
+        (1..10usize, 2..10usize).prop_flat_map(|(width, height)| {
+            let squares = width * height;
+            (
+                prop::collection::vec(prop::collection::vec(b'a'..=b'z', width), height),
+                0..squares,
+                1..squares,
+            )
+                .prop_map(move |(rows, start, step)| {
+                    let mut grid = Grid::from_rows(rows).unwrap();
+                    // a different square from the start for the destination
+                    let destination = (start + step) % squares;
+                    let start = (start % width, start / width);
+                    let destination = (destination % width, destination / width);
+                    grid[start] = b'a';
+                    grid[destination] = b'z';
+                    Heightmap(grid, start, destination)
+                })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn heightmap_round_trip(heightmap in heightmap()) {
+            prop_assert_eq!(parse_heightmap(&heightmap.to_string()).unwrap(), heightmap);
+        }
+    }
+
+    #[test]
+    fn generated_answers() {
+        for seed in 0..10 {
+            let generated = Day12::generate(seed, 40).unwrap();
+            let input = Day12::parse(&generated.input).unwrap();
+            assert_eq!(Day12::part1(&input).to_string(), generated.answers[0]);
+            assert_eq!(Day12::part2(&input).to_string(), generated.answers[1]);
+        }
+    }
+}
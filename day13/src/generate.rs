@@ -0,0 +1,99 @@
+use std::cmp::Ordering;
+
+use common::Generated;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// the generator keeps its own packets and compares them itself, so it doesn't lean on the
+// solution's `Ord`
+#[derive(Clone)]
+enum Packet {
+    Integer(u8),
+    List(Vec<Packet>),
+}
+
+// `size` pairs of packets
+pub fn generate(seed: u64, size: usize) -> Generated {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let dividers = [divider(2), divider(6)];
+
+    let mut packets = vec![];
+    while packets.len() < size.max(1) * 2 {
+        let packet = Packet::List(list(&mut rng, 0));
+        // a packet that matches a divider would make its position ambiguous
+        if dividers
+            .iter()
+            .all(|d| compare(&packet, d) != Ordering::Equal)
+        {
+            packets.push(packet);
+        }
+    }
+
+    let mut input = vec![];
+    let mut part1 = 0;
+    for (i, pair) in packets.chunks(2).enumerate() {
+        input.push(format!("{}\n{}\n", show(&pair[0]), show(&pair[1])));
+        if compare(&pair[0], &pair[1]) == Ordering::Less {
+            part1 += i + 1;
+        }
+    }
+
+    // each divider ends up after every packet smaller than it, and the 6 after the 2
+    let before = |d: &Packet| {
+        packets
+            .iter()
+            .filter(|p| compare(p, d) == Ordering::Less)
+            .count()
+    };
+    let part2 = (before(&dividers[0]) + 1) * (before(&dividers[1]) + 2);
+
+    Generated {
+        input: input.join("\n"),
+        answers: [part1.to_string(), part2.to_string()],
+    }
+}
+
+fn list(rng: &mut StdRng, depth: usize) -> Vec<Packet> {
+    (0..rng.gen_range(0..=5))
+        .map(|_| {
+            if depth < 4 && rng.gen_bool(0.3) {
+                Packet::List(list(rng, depth + 1))
+            } else {
+                Packet::Integer(rng.gen_range(0..=10))
+            }
+        })
+        .collect()
+}
+
+fn divider(value: u8) -> Packet {
+    Packet::List(vec![Packet::List(vec![Packet::Integer(value)])])
+}
+
+fn show(packet: &Packet) -> String {
+    match packet {
+        Packet::Integer(value) => value.to_string(),
+        Packet::List(items) => {
+            let items = items.iter().map(show).collect::<Vec<_>>();
+            format!("[{}]", items.join(","))
+        }
+    }
+}
+
+// the puzzle's rules, written out step by step
+fn compare(left: &Packet, right: &Packet) -> Ordering {
+    match (left, right) {
+        (Packet::Integer(l), Packet::Integer(r)) => l.cmp(r),
+        (Packet::Integer(_), Packet::List(_)) => compare(&Packet::List(vec![left.clone()]), right),
+        (Packet::List(_), Packet::Integer(_)) => compare(left, &Packet::List(vec![right.clone()])),
+        (Packet::List(l), Packet::List(r)) => {
+            for (a, b) in l.iter().zip(r) {
+                match compare(a, b) {
+                    Ordering::Equal => {}
+                    order => return order,
+                }
+            }
+            // whichever list ran out first is the smaller one
+            l.len().cmp(&r.len())
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use common::{Generated, ParseError, Solution};
+use serde::Deserialize;
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+
+const DAY: u8 = 13;
+
+mod generate;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<ListItem>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["13", "140"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_packets(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<Generated> {
+        Some(generate::generate(seed, size))
+    }
+}
+
+pub fn parse_packets(input: &str) -> Result<Vec<ListItem>, ParseError> {
+    let mut packets = vec![];
+    let mut last_line = (0, "");
+    for (i, l) in input.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
+        let packet = serde_json::from_str::<ListItem>(l).map_err(|e| {
+            // serde_json adds its own location to the message, we only want the reason
+            let message = e.to_string();
+            let message = message.split(" at line ").next().unwrap_or_default();
+            ParseError::new(DAY, l, e.column().max(1), message).offset_lines(i)
+        })?;
+        packets.push(packet);
+        last_line = (i, l);
+    }
+
+    // packets are compared in pairs, so there can't be one left over
+    if packets.len() % 2 == 1 {
+        let (i, l) = last_line;
+        return Err(
+            ParseError::missing(DAY, l, "expected another packet to pair this one with")
+                .offset_lines(i),
+        );
+    }
+    Ok(packets)
+}
+
+pub fn part1(packets: &[ListItem]) -> usize {
+    let mut lists = packets.iter();
+
+    let mut sum = 0;
+    for i in 1.. {
+        match is_next_pair_in_order(&mut lists) {
+            Some(true) => sum += i,
+            Some(false) => (),
+            None => break,
+        }
+    }
+    sum
+}
+
+fn is_next_pair_in_order<'a>(iter: &mut impl Iterator<Item = &'a ListItem>) -> Option<bool> {
+    let left = iter.next()?;
+    let right = iter.next().unwrap();
+
+    Some(left < right)
+}
+
+pub fn part2(packets: &[ListItem]) -> usize {
+    let dividers: (ListItem, ListItem) = (
+        serde_json::from_str::<ListItem>("[[2]]").unwrap(),
+        serde_json::from_str::<ListItem>("[[6]]").unwrap(),
+    );
+
+    // the dividers are tagged rather than found by comparison afterwards, as `[2]` compares equal
+    // to `[[2]]`, and go in last so the stable sort keeps them after any packet equal to them
+    let mut lists: Vec<(&ListItem, bool)> = packets.iter().map(|p| (p, false)).collect();
+    lists.push((&dividers.0, true));
+    lists.push((&dividers.1, true));
+
+    lists.sort_by(|a, b| a.0.cmp(b.0));
+
+    lists
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, divider))| *divider)
+        .map(|(i, _)| i + 1)
+        .product()
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ListItem {
+    Single(u8),
+    Nested(Vec<ListItem>),
+}
+
+impl PartialEq<Self> for ListItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for ListItem {}
+
+impl PartialOrd<Self> for ListItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for ListItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use ListItem::*;
+        match (self, other) {
+            (Single(a), Single(b)) => a.cmp(b),
+            (Single(a), Nested(b)) => [Single(*a)][..].cmp(b),
+            (Nested(a), Single(b)) => a.as_slice().cmp(&[Single(*b)]),
+            (Nested(a), Nested(b)) => a.cmp(b),
+        }
+    }
+}
+
+impl Display for ListItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListItem::Single(value) => write!(f, "{}", value),
+            ListItem::Nested(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day13::parse(Day13::EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input).to_string(), Day13::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day13::parse(Day13::EXAMPLE).unwrap();
+        assert_eq!(Day13::part2(&input).to_string(), Day13::EXAMPLE_ANSWERS[1]);
+    }
+
+    #[test]
+    fn packets_equal_to_a_divider() {
+        // `[2]` compares equal to the `[[2]]` divider but isn't it, the divider still goes in
+        // after it
+        let input = Day13::parse("[1]\n[3]\n\n[2]\n[5]\n").unwrap();
+        assert_eq!(part2(&input), 3 * 6);
+    }
+
+    #[test]
+    fn unpaired_packet() {
+        let e = Day13::parse("[1]\n[2]\n\n[3]\n").unwrap_err();
+        assert_eq!((e.line, e.text.as_str()), (4, "[3]"));
+    }
+
+    fn packet() -> impl Strategy<Value = ListItem> {
+        let leaf = any::<u8>().prop_map(ListItem::Single);
+        let item = leaf.prop_recursive(4, 32, 5, |inner| {
+            prop::collection::vec(inner, 0..5).prop_map(ListItem::Nested)
+        });
+        // packets are always lists at the top
+        prop::collection::vec(item, 0..5).prop_map(ListItem::Nested)
+    }
+
+    proptest! {
+        #[test]
+        fn packet_round_trip(pairs in prop::collection::vec((packet(), packet()), 0..3)) {
+            let packets: Vec<ListItem> = pairs.into_iter().flat_map(|(l, r)| [l, r]).collect();
+            let text = packets.iter().map(|p| format!("{}\n", p)).collect::<String>();
+            // `==` treats `1` and `[1]` the same, so compare the structure instead
+            prop_assert_eq!(
+                format!("{:?}", parse_packets(&text).unwrap()),
+                format!("{:?}", packets)
+            );
+        }
+    }
+
+    #[test]
+    fn generated_answers() {
+        for seed in 0..10 {
+            let generated = Day13::generate(seed, 200).unwrap();
+            let input = Day13::parse(&generated.input).unwrap();
+            assert_eq!(Day13::part1(&input).to_string(), generated.answers[0]);
+            assert_eq!(Day13::part2(&input).to_string(), generated.answers[1]);
+        }
+    }
+}
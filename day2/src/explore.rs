@@ -0,0 +1,125 @@
+use common::ParseError;
+
+use crate::{Game, Reading};
+
+/// One way of reading the guide's second column and the total it gives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpretation {
+    pub reading: Reading,
+    /// the second column's letters, in the game's shape order or as loss, draw, win
+    pub letters: Vec<char>,
+    pub score: usize,
+}
+
+impl Interpretation {
+    /// Like `X=rock Y=paper Z=scissors`
+    pub fn mapping(&self, game: &Game) -> String {
+        let mut pairs: Vec<(char, &str)> = match self.reading {
+            Reading::Shapes => self
+                .letters
+                .iter()
+                .zip(game.shapes())
+                .map(|(&letter, shape)| (letter, shape.as_str()))
+                .collect(),
+            Reading::Outcomes => self
+                .letters
+                .iter()
+                .zip(["loss", "draw", "win"])
+                .map(|(&letter, outcome)| (letter, outcome))
+                .collect(),
+        };
+        pairs.sort();
+        pairs
+            .iter()
+            .map(|(letter, meaning)| format!("{}={}", letter, meaning))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Scores the guide under every way of shuffling what the second column's letters mean, both
+/// as shapes and as outcomes
+pub fn explore(game: &Game, guide: &str) -> Result<Vec<Interpretation>, ParseError> {
+    let mut interpretations = vec![];
+    for letters in permutations(game.our_letters()) {
+        let shuffled = Game::new(
+            game.shapes().to_vec(),
+            game.their_letters().to_vec(),
+            letters.clone(),
+            game.outcome_letters(),
+            game.shape_scores().to_vec(),
+        )
+        .expect("a shuffled game is still valid");
+        interpretations.push(Interpretation {
+            reading: Reading::Shapes,
+            score: shuffled.score_guide(guide, Reading::Shapes)?,
+            letters,
+        });
+    }
+    for letters in permutations(&game.outcome_letters()) {
+        let shuffled = Game::new(
+            game.shapes().to_vec(),
+            game.their_letters().to_vec(),
+            game.our_letters().to_vec(),
+            [letters[0], letters[1], letters[2]],
+            game.shape_scores().to_vec(),
+        )
+        .expect("a shuffled game is still valid");
+        interpretations.push(Interpretation {
+            reading: Reading::Outcomes,
+            score: shuffled.score_guide(guide, Reading::Outcomes)?,
+            letters,
+        });
+    }
+    Ok(interpretations)
+}
+
+// every ordering of `items`, starting with the one given
+fn permutations(items: &[char]) -> Vec<Vec<char>> {
+    if items.len() <= 1 {
+        return vec![items.to_vec()];
+    }
+    let mut all = vec![];
+    for (i, &first) in items.iter().enumerate() {
+        let mut rest = items.to_vec();
+        rest.remove(i);
+        for mut tail in permutations(&rest) {
+            tail.insert(0, first);
+            all.push(tail);
+        }
+    }
+    all
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day2;
+    use common::Solution;
+
+    #[test]
+    fn every_reading_of_the_example() {
+        let game = Game::classic();
+        let interpretations = explore(&game, Day2::EXAMPLE).unwrap();
+        assert_eq!(interpretations.len(), 12);
+
+        // the puzzle's own readings come first
+        assert_eq!(interpretations[0].score, 15);
+        assert_eq!(
+            interpretations[0].mapping(&game),
+            "X=rock Y=paper Z=scissors"
+        );
+        assert_eq!(interpretations[6].score, 12);
+        assert_eq!(interpretations[6].mapping(&game), "X=loss Y=draw Z=win");
+
+        // losing every round, with scissors against rock, rock against paper and so on
+        let lowest = interpretations.iter().min_by_key(|i| i.score).unwrap();
+        assert_eq!(lowest.score, 6);
+        assert_eq!(lowest.mapping(&game), "X=rock Y=scissors Z=paper");
+        let highest = interpretations.iter().max_by_key(|i| i.score).unwrap();
+        assert_eq!(highest.score, 24);
+        assert_eq!(highest.mapping(&game), "X=scissors Y=paper Z=rock");
+
+        assert_eq!(explore(&Game::rpsls(), "A X\n").unwrap().len(), 120 + 6);
+    }
+}
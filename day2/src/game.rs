@@ -0,0 +1,309 @@
+use std::fmt;
+use std::sync::LazyLock;
+
+use common::ParseError;
+
+use crate::{guide_lines, Outcome, DAY, OUTCOME_SCORES};
+
+/// The puzzle's own game, built once for reading the guide's letters
+pub(crate) static CLASSIC: LazyLock<Game> = LazyLock::new(Game::classic);
+
+/// A hand game with any odd number of shapes in a circle, where every shape beats the half of
+/// the circle that comes before it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    shapes: Vec<String>,
+    /// the letter for each shape in the first column of a guide
+    theirs: Vec<char>,
+    /// the letter for each shape in the second column, when it's read as a shape
+    ours: Vec<char>,
+    /// the letters for a loss, draw and win, when the second column is read as an outcome
+    outcomes: [char; 3],
+    shape_scores: Vec<usize>,
+}
+
+/// How to read the second column of a strategy guide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    Shapes,
+    Outcomes,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    /// there has to be an odd number of shapes, and at least three, for every pair to have a winner
+    ShapeCount(usize),
+    /// a list that needs one entry per shape has the wrong number
+    Mismatch {
+        what: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    /// the same letter stands for two things in one column
+    DuplicateLetter(char),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::ShapeCount(n) => {
+                write!(
+                    f,
+                    "a game needs an odd number of shapes, at least 3, not {}",
+                    n
+                )
+            }
+            GameError::Mismatch {
+                what,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {} {}, one per shape, found {}",
+                expected, what, found
+            ),
+            GameError::DuplicateLetter(letter) => {
+                write!(f, "`{}` stands for more than one thing", letter)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+/// How shape `ours` does against shape `theirs` out of `n` in a circle
+pub(crate) fn cyclic_outcome(n: usize, ours: usize, theirs: usize) -> Outcome {
+    match (ours + n - theirs) % n {
+        0 => Outcome::Draw,
+        d if d <= n / 2 => Outcome::Win,
+        _ => Outcome::Loss,
+    }
+}
+
+impl Game {
+    /// `shapes` go in circle order, so each beats the `shapes.len() / 2` before it
+    pub fn new(
+        shapes: Vec<String>,
+        theirs: Vec<char>,
+        ours: Vec<char>,
+        outcomes: [char; 3],
+        shape_scores: Vec<usize>,
+    ) -> Result<Game, GameError> {
+        let n = shapes.len();
+        if n < 3 || n.is_multiple_of(2) {
+            return Err(GameError::ShapeCount(n));
+        }
+        for (what, found) in [
+            ("first column letters", theirs.len()),
+            ("second column letters", ours.len()),
+            ("shape scores", shape_scores.len()),
+        ] {
+            if found != n {
+                return Err(GameError::Mismatch {
+                    what,
+                    expected: n,
+                    found,
+                });
+            }
+        }
+        for letters in [&theirs[..], &ours[..], &outcomes[..]] {
+            if let Some(letter) = duplicate(letters) {
+                return Err(GameError::DuplicateLetter(letter));
+            }
+        }
+
+        Ok(Game {
+            shapes,
+            theirs,
+            ours,
+            outcomes,
+            shape_scores,
+        })
+    }
+
+    /// Rock, paper, scissors as the puzzle plays it
+    pub fn classic() -> Game {
+        Game::new(
+            names(&["rock", "paper", "scissors"]),
+            vec!['A', 'B', 'C'],
+            vec!['X', 'Y', 'Z'],
+            ['X', 'Y', 'Z'],
+            vec![1, 2, 3],
+        )
+        .unwrap()
+    }
+
+    /// Rock, paper, scissors, lizard, Spock, the classic three keeping their letters and scores,
+    /// lizard and Spock being D and E in the first column, V and W in the second, and scoring 4
+    /// and 5
+    pub fn rpsls() -> Game {
+        Game::new(
+            names(&["rock", "spock", "paper", "lizard", "scissors"]),
+            vec!['A', 'E', 'B', 'D', 'C'],
+            vec!['X', 'W', 'Y', 'V', 'Z'],
+            ['X', 'Y', 'Z'],
+            vec![1, 5, 2, 4, 3],
+        )
+        .unwrap()
+    }
+
+    pub fn shapes(&self) -> &[String] {
+        &self.shapes
+    }
+
+    pub fn their_letters(&self) -> &[char] {
+        &self.theirs
+    }
+
+    pub fn our_letters(&self) -> &[char] {
+        &self.ours
+    }
+
+    pub fn outcome_letters(&self) -> [char; 3] {
+        self.outcomes
+    }
+
+    pub fn shape_scores(&self) -> &[usize] {
+        &self.shape_scores
+    }
+
+    pub fn outcome(&self, ours: usize, theirs: usize) -> Outcome {
+        cyclic_outcome(self.shapes.len(), ours, theirs)
+    }
+
+    /// What a round is worth to us, the shape we played plus how it turned out
+    pub fn score(&self, ours: usize, theirs: usize) -> usize {
+        self.shape_scores[ours] + OUTCOME_SCORES[self.outcome(ours, theirs) as usize]
+    }
+
+    /// The shape to play against `theirs` to get `outcome`, the best scoring one when there's a
+    /// choice
+    pub fn for_outcome(&self, theirs: usize, outcome: Outcome) -> usize {
+        (0..self.shapes.len())
+            .filter(|&ours| self.outcome(ours, theirs) == outcome)
+            .max_by_key(|&ours| (self.shape_scores[ours], std::cmp::Reverse(ours)))
+            .unwrap()
+    }
+
+    /// Scores every round of a strategy guide, reading the second column as `reading` says
+    pub fn score_guide(&self, guide: &str, reading: Reading) -> Result<usize, ParseError> {
+        let mut total = 0;
+        for (i, line) in guide_lines(guide) {
+            let (theirs, second) = self
+                .parse_round(line, reading)
+                .map_err(|e| e.offset_lines(i))?;
+            let ours = match reading {
+                Reading::Shapes => second,
+                Reading::Outcomes => self.for_outcome(theirs, Outcome::ALL[second]),
+            };
+            total += self.score(ours, theirs);
+        }
+        Ok(total)
+    }
+
+    /// The highest scoring shape to play against `theirs`
+    pub fn best_response(&self, theirs: usize) -> usize {
+        (0..self.shapes.len())
+            .max_by_key(|&ours| (self.score(ours, theirs), std::cmp::Reverse(ours)))
+            .unwrap()
+    }
+
+    /// The opponent's shape in each round of a guide, whatever the second column says
+    pub fn their_shapes(&self, guide: &str) -> Result<Vec<usize>, ParseError> {
+        guide_lines(guide)
+            .map(|(i, line)| {
+                let Some(theirs) = line.split_whitespace().next() else {
+                    return Err(
+                        ParseError::missing(DAY, line, "expected their shape").offset_lines(i)
+                    );
+                };
+                self.their_shape(line, theirs)
+                    .map_err(|e| e.offset_lines(i))
+            })
+            .collect()
+    }
+
+    /// The most the guide's opponent could be beaten by, knowing every shape they'll play
+    pub fn max_score(&self, guide: &str) -> Result<usize, ParseError> {
+        Ok(self
+            .their_shapes(guide)?
+            .into_iter()
+            .map(|theirs| self.score(self.best_response(theirs), theirs))
+            .sum())
+    }
+
+    /// The opponent's shape, written as `token` which must be a slice of `line`
+    pub(crate) fn their_shape(&self, line: &str, token: &str) -> Result<usize, ParseError> {
+        letter(line, token, &self.theirs)
+    }
+
+    /// Where the second column's letter `token` comes in the shapes or the outcomes
+    pub(crate) fn second_column(
+        &self,
+        line: &str,
+        token: &str,
+        reading: Reading,
+    ) -> Result<usize, ParseError> {
+        match reading {
+            Reading::Shapes => letter(line, token, &self.ours),
+            Reading::Outcomes => letter(line, token, &self.outcomes),
+        }
+    }
+
+    /// The opponent's shape and the position of the second column's letter, the one place a line
+    /// of a guide is checked
+    pub(crate) fn parse_round(
+        &self,
+        line: &str,
+        reading: Reading,
+    ) -> Result<(usize, usize), ParseError> {
+        let mut columns = line.split_whitespace();
+        let (Some(theirs), Some(second)) = (columns.next(), columns.next()) else {
+            return Err(ParseError::missing(
+                DAY,
+                line,
+                "expected `<their shape> <response>`",
+            ));
+        };
+        if let Some(extra) = columns.next() {
+            return Err(ParseError::at(
+                DAY,
+                line,
+                extra,
+                format!("unexpected `{}` after the round", extra),
+            ));
+        }
+        Ok((
+            self.their_shape(line, theirs)?,
+            self.second_column(line, second, reading)?,
+        ))
+    }
+}
+
+fn letter(line: &str, token: &str, letters: &[char]) -> Result<usize, ParseError> {
+    let mut chars = token.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if let Some(index) = letters.iter().position(|&l| l == c) {
+            return Ok(index);
+        }
+    }
+    let expected: String = letters.iter().collect();
+    Err(ParseError::at(
+        DAY,
+        line,
+        token,
+        format!("unknown letter `{}`, expected one of {}", token, expected),
+    ))
+}
+
+fn names(shapes: &[&str]) -> Vec<String> {
+    shapes.iter().map(|name| name.to_string()).collect()
+}
+
+fn duplicate(letters: &[char]) -> Option<char> {
+    letters
+        .iter()
+        .enumerate()
+        .find(|(i, letter)| letters[..*i].contains(letter))
+        .map(|(_, &letter)| letter)
+}
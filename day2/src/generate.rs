@@ -0,0 +1,35 @@
+use common::Generated;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// `size` rounds, scored with arithmetic on the shapes rather than a lookup
+pub fn generate(seed: u64, size: usize) -> Generated {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut input = String::new();
+    let mut part1 = 0;
+    let mut part2 = 0;
+    for _ in 0..size.max(1) {
+        // 0 is rock, 1 paper and 2 scissors, each beats the one before it
+        let theirs: usize = rng.gen_range(0..3);
+        let column: usize = rng.gen_range(0..3);
+        input.push_str(&format!(
+            "{} {}\n",
+            (b'A' + theirs as u8) as char,
+            (b'X' + column as u8) as char
+        ));
+
+        // the second column as a shape, 0 is a draw, 1 a win and 2 a loss
+        let result = (column + 3 - theirs) % 3;
+        part1 += column + 1 + [3, 6, 0][result];
+
+        // the second column as an outcome, 0 is a loss, 1 a draw and 2 a win
+        let ours = (theirs + column + 2) % 3;
+        part2 += ours + 1 + column * 3;
+    }
+
+    Generated {
+        input,
+        answers: [part1.to_string(), part2.to_string()],
+    }
+}
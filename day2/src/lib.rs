@@ -0,0 +1,423 @@
+use common::{Generated, ParseError, Solution};
+
+use std::fmt;
+use std::str::FromStr;
+
+mod explore;
+mod game;
+mod generate;
+mod play;
+mod tournament;
+
+pub use explore::{explore, Interpretation};
+pub use game::{Game, GameError, Reading};
+pub use play::{parse_move, Session, Turn};
+pub use tournament::{
+    tournament, AlwaysWin, FixedGuide, FrequencyCounter, Match, SeededRandom, Standing, Strategy,
+    Tournament,
+};
+
+const DAY: u8 = 2;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Round>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["15", "12"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_rounds(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<Generated> {
+        Some(generate::generate(seed, size))
+    }
+}
+
+pub fn parse_rounds(input: &str) -> Result<Vec<Round>, ParseError> {
+    guide_lines(input)
+        .map(|(i, line)| line.parse::<Round>().map_err(|e| e.offset_lines(i)))
+        .collect()
+}
+
+// every line with something on it, blank ones aren't rounds
+pub(crate) fn guide_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+}
+
+/// The rounds that could be read from a guide, and why the rest couldn't
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LenientRounds {
+    pub rounds: Vec<Round>,
+    pub rejected: Vec<ParseError>,
+}
+
+/// Reads every round it can, skipping the lines that aren't rounds instead of stopping at them
+pub fn parse_rounds_lenient(input: &str) -> LenientRounds {
+    let mut rounds = vec![];
+    let mut rejected = vec![];
+    for (i, line) in guide_lines(input) {
+        match line.parse::<Round>() {
+            Ok(round) => rounds.push(round),
+            Err(e) => rejected.push(e.offset_lines(i)),
+        }
+    }
+    LenientRounds { rounds, rejected }
+}
+
+impl fmt::Display for LenientRounds {
+    /// A line for each rejected round, after a count of them
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.rounds.len() + self.rejected.len();
+        writeln!(f, "skipped {} of {} rounds", self.rejected.len(), total)?;
+        for e in &self.rejected {
+            writeln!(f, "  line {}: `{}`, {}", e.line, e.text, e.message)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+// the rule table, indexed by the enums above
+const SHAPE_SCORES: [usize; 3] = [1, 2, 3];
+const OUTCOME_SCORES: [usize; 3] = [0, 3, 6];
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    /// How playing this shape against `theirs` turns out, each shape beats the one before it
+    pub fn against(self, theirs: Shape) -> Outcome {
+        game::cyclic_outcome(3, self as usize, theirs as usize)
+    }
+
+    /// The shape to play against `theirs` to get `outcome`
+    pub fn for_outcome(theirs: Shape, outcome: Outcome) -> Shape {
+        Shape::ALL
+            .into_iter()
+            .find(|ours| ours.against(theirs) == outcome)
+            .unwrap()
+    }
+
+    pub fn score(self) -> usize {
+        SHAPE_SCORES[self as usize]
+    }
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    pub fn score(self) -> usize {
+        OUTCOME_SCORES[self as usize]
+    }
+}
+
+impl FromStr for Shape {
+    type Err = ParseError;
+
+    /// A shape as the opponent's column of the puzzle's guide writes it
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let theirs = game::CLASSIC.their_shape(s, s)?;
+        Ok(Shape::ALL[theirs])
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = ParseError;
+
+    /// An outcome as the puzzle's guide writes it in the second column
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let outcome = game::CLASSIC.second_column(s, s, Reading::Outcomes)?;
+        Ok(Outcome::ALL[outcome])
+    }
+}
+
+/// A line of the strategy guide, with its second column read both ways
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub theirs: Shape,
+    /// the second column as the shape to play, how part 1 reads it
+    pub ours: Shape,
+    /// the second column as how the round should end, how part 2 reads it
+    pub outcome: Outcome,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // the puzzle's game uses the same letters for the second column read either way, in the
+        // same order
+        let (theirs, second) = game::CLASSIC.parse_round(s, Reading::Shapes)?;
+        Ok(Round {
+            theirs: Shape::ALL[theirs],
+            ours: Shape::ALL[second],
+            outcome: Outcome::ALL[second],
+        })
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Shape::Rock => "rock",
+            Shape::Paper => "paper",
+            Shape::Scissors => "scissors",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Outcome::Loss => "loss",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for Round {
+    /// As a line of the guide, with the second column written as the shape we play
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            (b'A' + self.theirs as u8) as char,
+            (b'X' + self.ours as u8) as char
+        )
+    }
+}
+
+/// What a round is worth to us, the shape we played plus how it turned out
+pub fn score(ours: Shape, theirs: Shape) -> usize {
+    ours.score() + ours.against(theirs).score()
+}
+
+pub fn part1(rounds: &[Round]) -> usize {
+    rounds.iter().map(handle_round_pt1).sum()
+}
+
+pub fn handle_round_pt1(round: &Round) -> usize {
+    score(round.ours, round.theirs)
+}
+
+pub fn part2(rounds: &[Round]) -> usize {
+    rounds.iter().map(handle_round_pt2).sum()
+}
+
+pub fn handle_round_pt2(round: &Round) -> usize {
+    // now, X = lose, Y = draw, Z = win
+    score(
+        Shape::for_outcome(round.theirs, round.outcome),
+        round.theirs,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::{prop, prop_assert_eq, proptest, Strategy as _};
+
+    #[test]
+    fn part1_example() {
+        let input = Day2::parse(Day2::EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&input).to_string(), Day2::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2::parse(Day2::EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&input).to_string(), Day2::EXAMPLE_ANSWERS[1]);
+    }
+
+    #[test]
+    fn outcomes() {
+        assert_eq!(Shape::Paper.against(Shape::Rock), Outcome::Win);
+        assert_eq!(Shape::Rock.against(Shape::Paper), Outcome::Loss);
+        assert_eq!(Shape::Scissors.against(Shape::Paper), Outcome::Win);
+        assert_eq!(Shape::Rock.against(Shape::Scissors), Outcome::Win);
+        for theirs in Shape::ALL {
+            assert_eq!(theirs.against(theirs), Outcome::Draw);
+        }
+    }
+
+    #[test]
+    fn bad_rounds() {
+        let e = Day2::parse("A Y\nB Q\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        assert!(Day2::parse("A Y\nC\n").is_err());
+        // the opponent's column only takes A/B/C
+        let e = Day2::parse("X Y\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+
+        let e = Day2::parse("A Y\r\n\nB X Z\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 5));
+        assert_eq!(Day2::parse("A Y\r\n\n  C Z\n\n").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn lenient_parsing() {
+        let lenient = parse_rounds_lenient("A Y\nB\nB X\nrock Z\n\nC Z\nA Y X\n");
+        assert_eq!(part1(&lenient.rounds), 15);
+        let lines: Vec<usize> = lenient.rejected.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![2, 4, 7]);
+        assert_eq!(
+            lenient.to_string().lines().next(),
+            Some("skipped 3 of 6 rounds")
+        );
+
+        let clean = parse_rounds_lenient(Day2::EXAMPLE);
+        assert!(clean.rejected.is_empty());
+        assert_eq!(clean.rounds, Day2::parse(Day2::EXAMPLE).unwrap());
+    }
+
+    #[test]
+    fn classic_game_scores_like_the_puzzle() {
+        let game = Game::classic();
+        let example = Day2::EXAMPLE;
+        assert_eq!(game.score_guide(example, Reading::Shapes).unwrap(), 15);
+        assert_eq!(game.score_guide(example, Reading::Outcomes).unwrap(), 12);
+
+        let generated = Day2::generate(3, 200).unwrap();
+        assert_eq!(
+            game.score_guide(&generated.input, Reading::Shapes)
+                .unwrap()
+                .to_string(),
+            generated.answers[0]
+        );
+        assert_eq!(
+            game.score_guide(&generated.input, Reading::Outcomes)
+                .unwrap()
+                .to_string(),
+            generated.answers[1]
+        );
+    }
+
+    #[test]
+    fn rpsls() {
+        let game = Game::rpsls();
+        let shape = |name: &str| game.shapes().iter().position(|s| s == name).unwrap();
+        // every shape beats exactly two others
+        for (winner, loser) in [
+            ("scissors", "paper"),
+            ("paper", "rock"),
+            ("rock", "lizard"),
+            ("lizard", "spock"),
+            ("spock", "scissors"),
+            ("scissors", "lizard"),
+            ("lizard", "paper"),
+            ("paper", "spock"),
+            ("spock", "rock"),
+            ("rock", "scissors"),
+        ] {
+            assert_eq!(game.outcome(shape(winner), shape(loser)), Outcome::Win);
+            assert_eq!(game.outcome(shape(loser), shape(winner)), Outcome::Loss);
+        }
+
+        // lizard (4) beats paper, spock (5) loses to paper
+        assert_eq!(
+            game.score_guide("B V\nB W\n", Reading::Shapes).unwrap(),
+            10 + 5
+        );
+        // and the classic letters still mean the classic shapes
+        assert_eq!(
+            game.score_guide(Day2::EXAMPLE, Reading::Shapes).unwrap(),
+            15
+        );
+        // the best way to beat rock is spock
+        assert_eq!(game.score_guide("A Z\n", Reading::Outcomes).unwrap(), 11);
+
+        let e = game.score_guide("A V\nF V\n", Reading::Shapes).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+
+    #[test]
+    fn best_possible_score() {
+        // paper, scissors, rock wins all three
+        assert_eq!(Game::classic().max_score(Day2::EXAMPLE).unwrap(), 24);
+        for seed in 0..5 {
+            let guide = Day2::generate(seed, 200).unwrap().input;
+            let best = Game::classic().max_score(&guide).unwrap();
+            assert_eq!(
+                best,
+                200 * 6
+                    + guide
+                        .lines()
+                        .map(|l| match &l[..1] {
+                            "A" => 2,
+                            "B" => 3,
+                            _ => 1,
+                        })
+                        .sum::<usize>()
+            );
+        }
+    }
+
+    #[test]
+    fn games_need_an_odd_number_of_shapes() {
+        let four = Game::new(
+            vec!["a".into(), "b".into(), "c".into(), "d".into()],
+            vec!['A', 'B', 'C', 'D'],
+            vec!['W', 'X', 'Y', 'Z'],
+            ['X', 'Y', 'Z'],
+            vec![1, 2, 3, 4],
+        );
+        assert_eq!(four, Err(GameError::ShapeCount(4)));
+    }
+
+    fn round() -> impl proptest::strategy::Strategy<Value = Round> {
+        (0..3usize, 0..3usize).prop_map(|(theirs, second)| Round {
+            theirs: Shape::ALL[theirs],
+            ours: Shape::ALL[second],
+            outcome: [Outcome::Loss, Outcome::Draw, Outcome::Win][second],
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn rounds_round_trip(rounds in prop::collection::vec(round(), 0..10)) {
+            let text = rounds.iter().map(|r| format!("{}\n", r)).collect::<String>();
+            prop_assert_eq!(parse_rounds(&text).unwrap(), rounds);
+        }
+    }
+
+    #[test]
+    fn generated_answers() {
+        for seed in 0..10 {
+            let generated = Day2::generate(seed, 200).unwrap();
+            let input = Day2::parse(&generated.input).unwrap();
+            assert_eq!(Day2::part1(&input).to_string(), generated.answers[0]);
+            assert_eq!(Day2::part2(&input).to_string(), generated.answers[1]);
+        }
+    }
+}
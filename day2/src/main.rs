@@ -1,90 +1,228 @@
-fn main() {
-    println!("Hello, world!");
+use std::io;
+use std::path::PathBuf;
+
+use clap::{Parser, ValueEnum};
+use common::{print_answers, read_input, OrExit, Solution};
+use day2::{
+    AlwaysWin, Day2, FixedGuide, FrequencyCounter, Game, Interpretation, Reading, SeededRandom,
+    Session, Strategy,
+};
+
+/// Scores a rock paper scissors strategy guide
+#[derive(Parser)]
+struct Args {
+    /// The strategy guide, `-` reads from stdin
+    #[arg(required_unless_present_any = ["example", "play"])]
+    input: Option<PathBuf>,
+
+    /// Use the worked example from the puzzle statement instead
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+
+    /// Score the guide as a different game, the letters and scores below change it further
+    #[arg(long, value_enum)]
+    game: Option<Preset>,
+
+    /// The game's shapes, comma separated, in an order where each beats the half before it
+    #[arg(long, value_delimiter = ',')]
+    shapes: Option<Vec<String>>,
+
+    /// The first column's letter for each shape, like `ABC`
+    #[arg(long)]
+    theirs: Option<String>,
+
+    /// The second column's letter for each shape, like `XYZ`
+    #[arg(long)]
+    ours: Option<String>,
+
+    /// The second column's letters for a loss, a draw and a win
+    #[arg(long)]
+    outcomes: Option<String>,
+
+    /// What playing each shape is worth, comma separated
+    #[arg(long, value_delimiter = ',')]
+    scores: Option<Vec<usize>>,
 
-    let rounds = include_str!("./input")
-        .lines()
-        .map(|line| line.split_whitespace().collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+    /// Score the guide under every meaning the second column's letters could have
+    #[arg(long)]
+    explore: bool,
 
-    println!("Part 1: {:?}", part1(&rounds));
-    println!("Part 2: {:?}", part2(&rounds));
+    /// Print the most that could be scored against the guide's opponent
+    #[arg(long)]
+    best: bool,
+
+    /// Play the opponent's moves from the guide and a few other strategies against each other,
+    /// for this many rounds a match
+    #[arg(long, value_name = "ROUNDS")]
+    tournament: Option<usize>,
+
+    /// The seed for the random player, in a tournament or when playing against it
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Play rock paper scissors against this strategy, typing moves in
+    #[arg(long, value_enum, conflicts_with_all = ["input", "example"])]
+    play: Option<Opponent>,
+
+    /// Where to write the moves played as a strategy guide
+    #[arg(long, requires = "play")]
+    save: Option<PathBuf>,
+
+    /// Skip rounds that can't be read instead of stopping, listing them afterwards
+    #[arg(
+        long,
+        conflicts_with_all = ["game", "shapes", "theirs", "ours", "outcomes", "scores", "explore", "best", "tournament"]
+    )]
+    lenient: bool,
 }
 
-fn part1(rounds: &Vec<Vec<&str>>) -> usize {
-    rounds.iter().map(|round| handle_round_pt1(round)).sum()
+#[derive(Clone, Copy, ValueEnum)]
+enum Opponent {
+    AlwaysWin,
+    Frequency,
+    Random,
 }
 
-fn handle_round_pt1(round: &[&str]) -> usize {
-    enum RoundResult {
-        Win,
-        Draw,
-        Loss,
-    }
+#[derive(Clone, Copy, ValueEnum)]
+enum Preset {
+    Classic,
+    Rpsls,
+}
 
-    let result = match round[1] {
-        "X" => match round[0] {
-            "A" => RoundResult::Draw,
-            "B" => RoundResult::Loss,
-            "C" => RoundResult::Win,
-            _ => panic!("Invalid round"),
-        },
-        "Y" => match round[0] {
-            "A" => RoundResult::Win,
-            "B" => RoundResult::Draw,
-            "C" => RoundResult::Loss,
-            _ => panic!("Invalid round"),
-        },
-        "Z" => match round[0] {
-            "A" => RoundResult::Loss,
-            "B" => RoundResult::Win,
-            "C" => RoundResult::Draw,
-            _ => panic!("Invalid round"),
-        },
-        _ => panic!("Invalid round 4"),
-    };
+fn main() {
+    let args = Args::parse();
 
-    // get the score based on what we played
-    let score = match round[1] {
-        "X" => 1,
-        "Y" => 2,
-        "Z" => 3,
-        _ => 0,
+    if let Some(opponent) = args.play {
+        let strategy: Box<dyn Strategy> = match opponent {
+            Opponent::AlwaysWin => Box::<AlwaysWin>::default(),
+            Opponent::Frequency => Box::<FrequencyCounter>::default(),
+            Opponent::Random => Box::new(SeededRandom::new(args.seed)),
+        };
+        let mut session = Session::new(strategy);
+        session
+            .run(io::stdin().lock(), io::stdout(), args.save.as_deref())
+            .or_exit();
+        println!("Final score: {}", session.total());
+        return;
+    }
+    let input = match &args.input {
+        Some(path) => read_input(path).or_exit(),
+        None => Day2::EXAMPLE.to_string(),
     };
 
-    match result {
-        RoundResult::Win => score + 6,
-        RoundResult::Draw => score + 3,
-        RoundResult::Loss => score,
+    if args.best {
+        let game = game(&args).unwrap_or_else(Game::classic);
+        println!("Best possible: {}", game.max_score(&input).or_exit());
+        return;
     }
-}
 
-fn part2(rounds: &Vec<Vec<&str>>) -> usize {
-    rounds.iter().map(|round| handle_round_pt2(round)).sum()
+    if let Some(rounds) = args.tournament {
+        let game = game(&args).unwrap_or_else(Game::classic);
+        let mut players: Vec<Box<dyn Strategy>> = vec![
+            Box::new(FixedGuide::new(game.their_shapes(&input).or_exit())),
+            Box::new(AlwaysWin::default()),
+            Box::new(FrequencyCounter::default()),
+            Box::new(SeededRandom::new(args.seed)),
+        ];
+        print!("{}", day2::tournament(&game, &mut players, rounds));
+        return;
+    }
+
+    if args.explore {
+        explore(&game(&args).unwrap_or_else(Game::classic), &input);
+        return;
+    }
+
+    if args.lenient {
+        let lenient = day2::parse_rounds_lenient(&input);
+        if !lenient.rejected.is_empty() {
+            eprint!("{}", lenient);
+        }
+        println!("Part 1: {}", day2::part1(&lenient.rounds));
+        println!("Part 2: {}", day2::part2(&lenient.rounds));
+        return;
+    }
+
+    let Some(game) = game(&args) else {
+        print_answers::<Day2>(&input);
+        return;
+    };
+    for (part, reading) in [(1, Reading::Shapes), (2, Reading::Outcomes)] {
+        println!(
+            "Part {}: {}",
+            part,
+            game.score_guide(&input, reading).or_exit()
+        );
+    }
 }
 
-fn handle_round_pt2(round: &[&str]) -> usize {
-    // now, X = lose, Y = draw, Z = win
-    let score = match round[0] {
-        "A" => match round[1] {
-            "X" => 3,
-            "Y" => 1 + 3,
-            "Z" => 2 + 6,
-            _ => panic!("Invalid round"),
-        },
-        "B" => match round[1] {
-            "X" => 1,
-            "Y" => 2 + 3,
-            "Z" => 3 + 6,
-            _ => panic!("Invalid round"),
-        },
-        "C" => match round[1] {
-            "X" => 2,
-            "Y" => 3 + 3,
-            "Z" => 1 + 6,
-            _ => panic!("Invalid round"),
-        },
-        _ => panic!("Invalid round"),
+// the game described on the command line, if it's anything but the puzzle's
+fn game(args: &Args) -> Option<Game> {
+    let customised = args.shapes.is_some()
+        || args.theirs.is_some()
+        || args.ours.is_some()
+        || args.outcomes.is_some()
+        || args.scores.is_some();
+    if args.game.is_none() && !customised {
+        return None;
+    }
+
+    let base = match args.game.unwrap_or(Preset::Classic) {
+        Preset::Classic => Game::classic(),
+        Preset::Rpsls => Game::rpsls(),
     };
+    let letters = |letters: &Option<String>| letters.as_ref().map(|l| l.chars().collect());
+    let outcomes = match &args.outcomes {
+        Some(letters) => letters
+            .chars()
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| format!("expected three outcome letters, not `{}`", letters))
+            .or_exit(),
+        None => base.outcome_letters(),
+    };
+    let game = Game::new(
+        args.shapes
+            .clone()
+            .unwrap_or_else(|| base.shapes().to_vec()),
+        letters(&args.theirs).unwrap_or_else(|| base.their_letters().to_vec()),
+        letters(&args.ours).unwrap_or_else(|| base.our_letters().to_vec()),
+        outcomes,
+        args.scores
+            .clone()
+            .unwrap_or_else(|| base.shape_scores().to_vec()),
+    );
+    Some(game.or_exit())
+}
 
-    score
+fn explore(game: &Game, guide: &str) {
+    let mut interpretations = day2::explore(game, guide).or_exit();
+    interpretations.sort_by_key(|i| std::cmp::Reverse(i.score));
+
+    for (reading, heading) in [
+        (Reading::Shapes, "Second column as the shape to play:"),
+        (Reading::Outcomes, "Second column as how the round ends:"),
+    ] {
+        println!("{}", heading);
+        for interpretation in interpretations.iter().filter(|i| i.reading == reading) {
+            println!(
+                "  {:<40} {:>8}",
+                interpretation.mapping(game),
+                interpretation.score
+            );
+        }
+    }
+
+    let describe = |i: &Interpretation| {
+        let reading = match i.reading {
+            Reading::Shapes => "shapes",
+            Reading::Outcomes => "outcomes",
+        };
+        format!("{} reading it as {}, {}", i.score, reading, i.mapping(game))
+    };
+    println!("Highest: {}", describe(&interpretations[0]));
+    println!(
+        "Lowest: {}",
+        describe(&interpretations[interpretations.len() - 1])
+    );
 }
@@ -0,0 +1,192 @@
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use crate::{handle_round_pt1, Game, Outcome, Round, Shape, Strategy};
+
+/// Reads a move typed by a player, a letter from the guide's first column or the shape's name
+pub fn parse_move(s: &str) -> Option<Shape> {
+    match s.trim().to_ascii_lowercase().as_str() {
+        "a" | "rock" => Some(Shape::Rock),
+        "b" | "paper" => Some(Shape::Paper),
+        "c" | "scissors" => Some(Shape::Scissors),
+        _ => None,
+    }
+}
+
+/// A game against someone at the keyboard, us answering their moves from a strategy
+pub struct Session {
+    game: Game,
+    strategy: Box<dyn Strategy>,
+    rounds: Vec<Round>,
+    total: usize,
+}
+
+/// How a round of a session went, scored for us
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub round: Round,
+    pub shape_score: usize,
+    pub outcome_score: usize,
+    /// everything scored so far, this round included
+    pub total: usize,
+}
+
+impl Turn {
+    pub fn score(&self) -> usize {
+        self.shape_score + self.outcome_score
+    }
+}
+
+impl Session {
+    pub fn new(strategy: Box<dyn Strategy>) -> Session {
+        Session {
+            game: Game::classic(),
+            strategy,
+            rounds: vec![],
+            total: 0,
+        }
+    }
+
+    pub fn play(&mut self, theirs: Shape) -> Turn {
+        // the classic game numbers its shapes the same as `Shape`
+        let ours = Shape::ALL[self.strategy.choose(&self.game)];
+        self.strategy.observe(ours as usize, theirs as usize);
+
+        // the round as the saved guide will read back, the letter written for our shape
+        // standing for the outcome in the same place
+        let round = Round {
+            theirs,
+            ours,
+            outcome: Outcome::ALL[ours as usize],
+        };
+        self.rounds.push(round);
+
+        let score = handle_round_pt1(&round);
+        self.total += score;
+        Turn {
+            round,
+            shape_score: ours.score(),
+            outcome_score: score - ours.score(),
+            total: self.total,
+        }
+    }
+
+    /// The rounds played so far, as the saved guide reads back
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The session so far as a strategy guide, the second column being what we played
+    pub fn guide(&self) -> String {
+        self.rounds
+            .iter()
+            .map(|round| {
+                format!(
+                    "{} {}\n",
+                    self.game.their_letters()[round.theirs as usize],
+                    self.game.our_letters()[round.ours as usize]
+                )
+            })
+            .collect()
+    }
+
+    /// Plays until `input` runs out or says `quit`, the guide is written to `save_to` when asked
+    /// with `save` and again at the end
+    pub fn run(
+        &mut self,
+        input: impl BufRead,
+        mut output: impl Write,
+        save_to: Option<&Path>,
+    ) -> io::Result<()> {
+        writeln!(
+            output,
+            "Play A/B/C or rock/paper/scissors, `save` to write out the guide, `quit` to stop"
+        )?;
+        for line in input.lines() {
+            let line = line?;
+            match line.trim() {
+                "" => continue,
+                "quit" | "q" => break,
+                "save" => {
+                    match save_to {
+                        Some(path) => self.save(path, &mut output)?,
+                        None => writeln!(output, "Nowhere to save to, start with --save FILE")?,
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+
+            let Some(theirs) = parse_move(&line) else {
+                writeln!(output, "`{}` isn't a move", line.trim())?;
+                continue;
+            };
+            let turn = self.play(theirs);
+            writeln!(
+                output,
+                "{} against {}: {} for {} plus {} for the {}, {} this round, {} in all",
+                turn.round.ours,
+                turn.round.theirs,
+                turn.shape_score,
+                turn.round.ours,
+                turn.outcome_score,
+                turn.round.ours.against(turn.round.theirs),
+                turn.score(),
+                turn.total
+            )?;
+        }
+
+        if let Some(path) = save_to {
+            self.save(path, &mut output)?;
+        }
+        Ok(())
+    }
+
+    fn save(&self, path: &Path, mut output: impl Write) -> io::Result<()> {
+        fs::write(path, self.guide())?;
+        writeln!(
+            output,
+            "Saved {} rounds to {}",
+            self.rounds.len(),
+            path.display()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AlwaysWin, Day2};
+    use common::Solution;
+
+    #[test]
+    fn interactive_session() {
+        let mut session = Session::new(Box::new(AlwaysWin::default()));
+        let typed = "rock\nB\n\nscissors\nlizard\nc\nquit\nA\n";
+        let mut shown = vec![];
+        let path = std::env::temp_dir().join(format!("day2-session-{}", std::process::id()));
+        session
+            .run(typed.as_bytes(), &mut shown, Some(&path))
+            .unwrap();
+        let shown = String::from_utf8(shown).unwrap();
+        let saved = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(shown.contains("rock against rock: 1 for rock plus 3 for the draw, 4 this round"));
+        assert!(shown.contains("`lizard` isn't a move"));
+        // nothing after `quit` is played
+        assert_eq!(session.guide(), "A X\nB Y\nC Z\nC X\n");
+
+        // the saved guide reads back as the shapes played and scores the same as the session did
+        assert_eq!(saved, session.guide());
+        let guide = Day2::parse(&saved).unwrap();
+        assert_eq!(guide, session.rounds());
+        assert_eq!(Day2::part1(&guide), session.total());
+        assert_eq!(parse_move(" Paper "), Some(Shape::Paper));
+    }
+}
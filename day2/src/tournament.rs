@@ -0,0 +1,314 @@
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::{Game, Outcome};
+
+/// A way of picking shapes round after round, knowing only what's been played so far
+pub trait Strategy {
+    fn name(&self) -> String;
+
+    /// The shape to play next
+    fn choose(&mut self, game: &Game) -> usize;
+
+    /// Told what both sides played once a round is over
+    fn observe(&mut self, _ours: usize, _theirs: usize) {}
+
+    /// Forgets everything before a new match
+    fn reset(&mut self) {}
+}
+
+/// Plays a fixed list of shapes in order, starting over when it runs out
+pub struct FixedGuide {
+    shapes: Vec<usize>,
+    next: usize,
+}
+
+impl FixedGuide {
+    pub fn new(shapes: Vec<usize>) -> FixedGuide {
+        FixedGuide { shapes, next: 0 }
+    }
+}
+
+impl Strategy for FixedGuide {
+    fn name(&self) -> String {
+        "guide".to_string()
+    }
+
+    fn choose(&mut self, _game: &Game) -> usize {
+        let shape = self.shapes.get(self.next).copied().unwrap_or(0);
+        self.next = (self.next + 1) % self.shapes.len().max(1);
+        shape
+    }
+
+    fn reset(&mut self) {
+        self.next = 0;
+    }
+}
+
+/// Goes for the win every round, betting the opponent plays the same as last time
+#[derive(Default)]
+pub struct AlwaysWin {
+    last: Option<usize>,
+}
+
+impl Strategy for AlwaysWin {
+    fn name(&self) -> String {
+        "always-win".to_string()
+    }
+
+    fn choose(&mut self, game: &Game) -> usize {
+        match self.last {
+            Some(theirs) => game.for_outcome(theirs, Outcome::Win),
+            None => 0,
+        }
+    }
+
+    fn observe(&mut self, _ours: usize, theirs: usize) {
+        self.last = Some(theirs);
+    }
+
+    fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+/// Plays whatever scores best on average against the shapes the opponent has played so far
+#[derive(Default)]
+pub struct FrequencyCounter {
+    counts: Vec<usize>,
+}
+
+impl Strategy for FrequencyCounter {
+    fn name(&self) -> String {
+        "frequency".to_string()
+    }
+
+    fn choose(&mut self, game: &Game) -> usize {
+        let n = game.shapes().len();
+        self.counts.resize(n, 0);
+        (0..n)
+            .max_by_key(|&ours| {
+                let expected: usize = (0..n)
+                    .map(|theirs| self.counts[theirs] * game.score(ours, theirs))
+                    .sum();
+                (expected, std::cmp::Reverse(ours))
+            })
+            .unwrap()
+    }
+
+    fn observe(&mut self, _ours: usize, theirs: usize) {
+        if self.counts.len() <= theirs {
+            self.counts.resize(theirs + 1, 0);
+        }
+        self.counts[theirs] += 1;
+    }
+
+    fn reset(&mut self) {
+        self.counts.clear();
+    }
+}
+
+/// Picks shapes at random, the same ones every match for the same seed
+pub struct SeededRandom {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl SeededRandom {
+    pub fn new(seed: u64) -> SeededRandom {
+        SeededRandom {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Strategy for SeededRandom {
+    fn name(&self) -> String {
+        format!("random({})", self.seed)
+    }
+
+    fn choose(&mut self, game: &Game) -> usize {
+        self.rng.gen_range(0..game.shapes().len())
+    }
+
+    fn reset(&mut self) {
+        self.rng = StdRng::seed_from_u64(self.seed);
+    }
+}
+
+/// How one strategy did over all its matches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    /// its total score in each match it played
+    pub match_scores: Vec<usize>,
+}
+
+impl Standing {
+    pub fn rounds(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    /// The share of rounds won, from 0 to 1
+    pub fn win_rate(&self) -> f64 {
+        self.wins as f64 / self.rounds().max(1) as f64
+    }
+}
+
+/// A match between two strategies, `players` index the tournament's standings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub players: [usize; 2],
+    pub scores: [usize; 2],
+    pub wins: [usize; 2],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tournament {
+    pub standings: Vec<Standing>,
+    pub matches: Vec<Match>,
+}
+
+/// Every strategy plays every other for `rounds` rounds
+pub fn tournament(game: &Game, players: &mut [Box<dyn Strategy>], rounds: usize) -> Tournament {
+    let mut standings: Vec<Standing> = players
+        .iter()
+        .map(|player| Standing {
+            name: player.name(),
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            match_scores: vec![],
+        })
+        .collect();
+    let mut matches = vec![];
+
+    for a in 0..players.len() {
+        for b in a + 1..players.len() {
+            let (left, right) = players.split_at_mut(b);
+            let played = play(game, &mut *left[a], &mut *right[0], rounds);
+            let draws = rounds - played.wins[0] - played.wins[1];
+            for (side, player) in [a, b].into_iter().enumerate() {
+                let standing = &mut standings[player];
+                standing.wins += played.wins[side];
+                standing.losses += played.wins[1 - side];
+                standing.draws += draws;
+                standing.match_scores.push(played.scores[side]);
+            }
+            matches.push(Match {
+                players: [a, b],
+                ..played
+            });
+        }
+    }
+
+    Tournament { standings, matches }
+}
+
+fn play(game: &Game, a: &mut dyn Strategy, b: &mut dyn Strategy, rounds: usize) -> Match {
+    a.reset();
+    b.reset();
+    let mut played = Match {
+        players: [0, 1],
+        scores: [0, 0],
+        wins: [0, 0],
+    };
+    for _ in 0..rounds {
+        let (shape_a, shape_b) = (a.choose(game), b.choose(game));
+        played.scores[0] += game.score(shape_a, shape_b);
+        played.scores[1] += game.score(shape_b, shape_a);
+        match game.outcome(shape_a, shape_b) {
+            Outcome::Win => played.wins[0] += 1,
+            Outcome::Loss => played.wins[1] += 1,
+            Outcome::Draw => {}
+        }
+        a.observe(shape_a, shape_b);
+        b.observe(shape_b, shape_a);
+    }
+    played
+}
+
+impl fmt::Display for Tournament {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<14} {:>8} {:>7} {:>7} {:>7} {:>9}   match scores (min / median / max)",
+            "Strategy", "Win rate", "Wins", "Draws", "Losses", "Mean"
+        )?;
+        for standing in &self.standings {
+            let mut scores = standing.match_scores.clone();
+            scores.sort_unstable();
+            let mean = scores.iter().sum::<usize>() as f64 / scores.len().max(1) as f64;
+            let (min, median, max) = match scores.len() {
+                0 => (0, 0, 0),
+                n => (scores[0], scores[n / 2], scores[n - 1]),
+            };
+            writeln!(
+                f,
+                "{:<14} {:>7.1}% {:>7} {:>7} {:>7} {:>9.1}   {} / {} / {}",
+                standing.name,
+                standing.win_rate() * 100.0,
+                standing.wins,
+                standing.draws,
+                standing.losses,
+                mean,
+                min,
+                median,
+                max
+            )?;
+        }
+
+        writeln!(f)?;
+        for played in &self.matches {
+            let [a, b] = played.players;
+            writeln!(
+                f,
+                "{} vs {}: {} to {}, {} wins to {}",
+                self.standings[a].name,
+                self.standings[b].name,
+                played.scores[0],
+                played.scores[1],
+                played.wins[0],
+                played.wins[1]
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tournaments() {
+        let game = Game::classic();
+        let mut players: Vec<Box<dyn Strategy>> = vec![
+            Box::new(FixedGuide::new(vec![0])),
+            Box::new(AlwaysWin::default()),
+            Box::new(FrequencyCounter::default()),
+            Box::new(SeededRandom::new(7)),
+        ];
+        let played = tournament(&game, &mut players, 10);
+        assert_eq!(played.matches.len(), 6);
+
+        // after a first round of rock against rock, both adaptive players win every round
+        for (i, played) in played.matches.iter().take(2).enumerate() {
+            assert_eq!(played.players, [0, i + 1]);
+            assert_eq!(played.wins, [0, 9]);
+        }
+
+        for standing in &played.standings {
+            assert_eq!(standing.rounds(), 30);
+            assert_eq!(standing.match_scores.len(), 3);
+        }
+        // random play is the same every time for a seed
+        assert_eq!(tournament(&game, &mut players, 10), played);
+    }
+}
@@ -0,0 +1,82 @@
+use common::{ParseError, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["157", "70"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+pub fn part1(rucksacks: &[&str]) -> usize {
+    rucksacks
+        .iter()
+        .filter_map(|&rucksack| {
+            let (l, r) = rucksack.split_at(rucksack.len() / 2);
+            char_in_all_string(vec![l, r])
+        })
+        .map(|f| get_prio(f as u8))
+        .sum()
+}
+
+pub fn part2(rucksacks: &[&str]) -> usize {
+    // grab every three lines
+    rucksacks
+        .chunks(3)
+        .map(|r| r.to_vec())
+        .filter_map(char_in_all_string)
+        .map(|c| get_prio(c as u8))
+        .sum()
+}
+
+fn char_in_all_string(strings: Vec<&str>) -> Option<char> {
+    let mut chars = strings[0].chars();
+    let mut char = chars.next();
+    while char.is_some() {
+        let c = char.unwrap();
+        if strings.iter().all(|s| s.contains(c)) {
+            return Some(c);
+        }
+        char = chars.next();
+    }
+    None
+}
+
+fn get_prio(item: u8) -> usize {
+    if item >= b'a' {
+        return (item - b'a') as usize + 1;
+    }
+    (item - b'A') as usize + 27
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day3::parse(Day3::EXAMPLE).unwrap();
+        assert_eq!(Day3::part1(&input).to_string(), Day3::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day3::parse(Day3::EXAMPLE).unwrap();
+        assert_eq!(Day3::part2(&input).to_string(), Day3::EXAMPLE_ANSWERS[1]);
+    }
+}
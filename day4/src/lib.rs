@@ -0,0 +1,140 @@
+use common::{parse_token, ParseError, Solution};
+
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+
+const DAY: u8 = 4;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["2", "4"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_pairs(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+/// The sections assigned to each elf of a pair
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pair(pub RangeInclusive<usize>, pub RangeInclusive<usize>);
+
+impl Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{},{}-{}",
+            self.0.start(),
+            self.0.end(),
+            self.1.start(),
+            self.1.end()
+        )
+    }
+}
+
+pub fn parse_pairs(input: &str) -> Result<Vec<Pair>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| parse_pair(l).map_err(|e| e.offset_lines(i)))
+        .collect::<Result<Vec<_>, _>>()
+}
+
+fn parse_pair(line: &str) -> Result<Pair, ParseError> {
+    let Some((a, b)) = line.split_once(',') else {
+        return Err(ParseError::missing(
+            DAY,
+            line,
+            "expected a `,` between two ranges",
+        ));
+    };
+    Ok(Pair(
+        to_range_inclusive(line, a)?,
+        to_range_inclusive(line, b)?,
+    ))
+}
+
+pub fn part1(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .filter(|pair| contains(&pair.0, &pair.1))
+        .count()
+}
+
+pub fn part2(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .filter(|pair| overlaps(&pair.0, &pair.1))
+        .count()
+}
+
+fn to_range_inclusive(line: &str, input: &str) -> Result<RangeInclusive<usize>, ParseError> {
+    let Some((start, end)) = input.split_once('-') else {
+        return Err(ParseError::at(
+            DAY,
+            line,
+            input,
+            "expected a range like `2-4`",
+        ));
+    };
+    let start = parse_token(DAY, line, start)?;
+    let end = parse_token(DAY, line, end)?;
+    Ok(start..=end)
+}
+
+fn contains(a: &RangeInclusive<usize>, b: &RangeInclusive<usize>) -> bool {
+    a.contains(b.start()) && a.contains(b.end()) || b.contains(a.start()) && b.contains(a.end())
+}
+
+fn overlaps(a: &RangeInclusive<usize>, b: &RangeInclusive<usize>) -> bool {
+    a.contains(b.start()) || a.contains(b.end()) || b.contains(a.start()) || b.contains(a.end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day4::parse(Day4::EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&input).to_string(), Day4::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day4::parse(Day4::EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&input).to_string(), Day4::EXAMPLE_ANSWERS[1]);
+    }
+
+    fn pair() -> impl Strategy<Value = Pair> {
+        (
+            any::<usize>(),
+            any::<usize>(),
+            any::<usize>(),
+            any::<usize>(),
+        )
+            .prop_map(|(a, b, c, d)| Pair(a..=b, c..=d))
+    }
+
+    proptest! {
+        #[test]
+        fn pairs_round_trip(pairs in prop::collection::vec(pair(), 0..10)) {
+            let text = pairs.iter().map(|p| format!("{}\n", p)).collect::<String>();
+            prop_assert_eq!(parse_pairs(&text).unwrap(), pairs);
+        }
+    }
+}
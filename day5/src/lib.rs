@@ -0,0 +1,348 @@
+use common::{parse_token, Frame, ParseError, Simulation, Solution};
+use regex::Regex;
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+const DAY: u8 = 5;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = (Grid, Vec<Move>);
+    type Part1 = String;
+    type Part2 = String;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["CMZ", "MCD"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(&input.0, &input.1)
+    }
+
+    fn simulate(input: &Self::Input<'_>, part: u8) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(Crane::new(input.0.clone(), input.1.clone(), part)))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Grid, Vec<Move>), ParseError> {
+    let Some((drawing, procedure)) = input.split_once("\n\n") else {
+        return Err(ParseError::missing(
+            DAY,
+            input.lines().last().unwrap_or_default(),
+            "expected a blank line between the stacks and the moves",
+        )
+        .offset_lines(input.lines().count().saturating_sub(1)));
+    };
+
+    let grid: Grid = drawing.parse()?;
+    // the moves start after the drawing and the blank line
+    let offset = drawing.lines().count() + 1;
+    // both cranes move the same number of crates, so the stack heights follow either part
+    let mut heights: Vec<usize> = grid.0.iter().map(|stack| stack.len()).collect();
+    let mut moves = vec![];
+    for (i, line) in procedure.lines().enumerate() {
+        let error =
+            |message: String| ParseError::new(DAY, line, 1, message).offset_lines(offset + i);
+        let m: Move = line
+            .parse()
+            .map_err(|e: ParseError| e.offset_lines(offset + i))?;
+        for stack in [m.from, m.to] {
+            if stack == 0 || stack > grid.0.len() {
+                return Err(error(format!("there is no stack {}", stack)));
+            }
+        }
+        if m.amount > heights[m.from - 1] {
+            return Err(error(format!(
+                "stack {} only has {} crates by now, so {} can't be moved",
+                m.from,
+                heights[m.from - 1],
+                m.amount
+            )));
+        }
+        heights[m.from - 1] -= m.amount;
+        heights[m.to - 1] += m.amount;
+        moves.push(m);
+    }
+
+    Ok((grid, moves))
+}
+
+pub fn part1(grid: &Grid, moves: &[Move]) -> String {
+    let mut pt1_grid = grid.clone();
+    for m in moves {
+        pt1_grid.apply_move_pt1(m);
+    }
+    pt1_grid.get_top_of_stacks()
+}
+
+pub fn part2(grid: &Grid, moves: &[Move]) -> String {
+    let mut pt2_grid = grid.clone();
+    for m in moves {
+        pt2_grid.apply_move_pt2(m);
+    }
+    pt2_grid.get_top_of_stacks()
+}
+
+/// The crane working through the moves one at a time, moving crates like part 1 or part 2 says
+pub struct Crane {
+    grid: Grid,
+    moves: Vec<Move>,
+    done: usize,
+    part: u8,
+}
+
+impl Crane {
+    pub fn new(grid: Grid, moves: Vec<Move>, part: u8) -> Crane {
+        Crane {
+            grid,
+            moves,
+            done: 0,
+            part,
+        }
+    }
+}
+
+impl Simulation for Crane {
+    fn frame(&self) -> Frame {
+        let last = match self.done {
+            0 => "the starting stacks".to_string(),
+            done => self.moves[done - 1].to_string(),
+        };
+        Frame {
+            caption: format!(
+                "{} ({} of {}), top of the stacks {}",
+                last,
+                self.done,
+                self.moves.len(),
+                self.grid.get_top_of_stacks()
+            ),
+            picture: self.grid.to_string(),
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(m) = self.moves.get(self.done) else {
+            return false;
+        };
+        if self.part == 1 {
+            self.grid.apply_move_pt1(m);
+        } else {
+            self.grid.apply_move_pt2(m);
+        }
+        self.done += 1;
+        true
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid(Vec<Vec<char>>);
+
+impl Grid {
+    fn apply_move_pt1(&mut self, m: &Move) {
+        for _ in 0..m.amount {
+            // pop from the from row, push to the to row
+            let c = self.0[m.from - 1].pop().unwrap();
+            self.0[m.to - 1].push(c);
+        }
+    }
+
+    fn apply_move_pt2(&mut self, m: &Move) {
+        let mut to_move: Vec<char> = vec![];
+        for _ in 0..m.amount {
+            // pop from the from row, push to the to row
+            let c = self.0[m.from - 1].pop().unwrap();
+            to_move.push(c);
+        }
+        // this is lazy, but reverse it and push
+        to_move.reverse();
+        for c in to_move {
+            self.0[m.to - 1].push(c);
+        }
+    }
+
+    fn get_top_of_stacks(&self) -> String {
+        let mut result = String::new();
+        // stacks can be empty partway through
+        for row in &self.0 {
+            result.extend(row.last());
+        }
+        result.to_owned()
+    }
+}
+
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+        let Some((numbers, _)) = lines.split_last() else {
+            return Err(ParseError::missing(
+                DAY,
+                s,
+                "expected a drawing of the stacks",
+            ));
+        };
+
+        // pad out the drawing so it can be read a column at a time, which also means it is
+        // always rectangular
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let drawing = grid::Grid::from_rows(
+            lines
+                .iter()
+                .map(|l| l.chars().chain(std::iter::repeat(' ')).take(width)),
+        )
+        .unwrap();
+
+        // crates sit above the numbers, which are every 4 characters starting at the second
+        let num_vecs = numbers.len() / 4 + 1;
+        for ((x, y), c) in drawing.iter() {
+            if y + 1 < drawing.height() && x % 4 == 1 && x / 4 >= num_vecs && *c != ' ' {
+                return Err(ParseError::new(
+                    DAY,
+                    lines[y],
+                    x + 1,
+                    "crate is outside of the numbered stacks",
+                )
+                .offset_lines(y));
+            }
+        }
+
+        let stacks = (0..num_vecs)
+            .map(|i| match i * 4 + 1 {
+                x if x < width => drawing
+                    .column(x)
+                    .rev()
+                    .skip(1)
+                    .copied()
+                    .filter(|c| !c.is_whitespace())
+                    .collect(),
+                _ => vec![],
+            })
+            .collect();
+        Ok(Grid(stacks))
+    }
+}
+
+// draws the stacks the way the puzzle does, which only lines up for up to 9 stacks
+impl Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let height = self.0.iter().map(Vec::len).max().unwrap_or(0);
+        for y in (0..height).rev() {
+            let row = self
+                .0
+                .iter()
+                .map(|stack| match stack.get(y) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        let numbers = (1..=self.0.len())
+            .map(|i| format!(" {} ", i))
+            .collect::<Vec<_>>();
+        writeln!(f, "{}", numbers.join(" "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub from: usize,
+    pub to: usize,
+    pub amount: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"move (\d+) from (\d+) to (\d+)").unwrap();
+        let Some(caps) = re.captures(s) else {
+            return Err(ParseError::new(
+                DAY,
+                s,
+                1,
+                "expected `move <amount> from <stack> to <stack>`",
+            ));
+        };
+
+        let amount = parse_token(DAY, s, &caps[1])?;
+        let from = parse_token(DAY, s, &caps[2])?;
+        let to = parse_token(DAY, s, &caps[3])?;
+        Ok(Move { from, to, amount })
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.amount, self.from, self.to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::parse(Day5::EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input).to_string(), Day5::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::parse(Day5::EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input).to_string(), Day5::EXAMPLE_ANSWERS[1]);
+    }
+
+    #[test]
+    fn moving_too_many_crates() {
+        let input = Day5::EXAMPLE.replacen("move 1 from 2 to 1", "move 9 from 2 to 1", 1);
+        let e = Day5::parse(&input).unwrap_err();
+        assert_eq!((e.line, e.text.as_str()), (6, "move 9 from 2 to 1"));
+    }
+
+    #[test]
+    fn crane_finishes_with_the_answer() {
+        let (grid, moves) = Day5::parse(Day5::EXAMPLE).unwrap();
+        for part in [1, 2] {
+            let mut crane = Crane::new(grid.clone(), moves.clone(), part);
+            while crane.step() {}
+            assert!(crane
+                .frame()
+                .caption
+                .ends_with(Day5::EXAMPLE_ANSWERS[part as usize - 1]));
+        }
+    }
+
+    fn grid() -> impl Strategy<Value = Grid> {
+        prop::collection::vec(
+            prop::collection::vec(prop::char::range('A', 'Z'), 0..8),
+            1..=9,
+        )
+        .prop_map(Grid)
+    }
+
+    proptest! {
+        #[test]
+        fn grid_round_trip(grid in grid()) {
+            prop_assert_eq!(grid.to_string().parse::<Grid>().unwrap(), grid);
+        }
+
+        #[test]
+        fn move_round_trip(amount: usize, from: usize, to: usize) {
+            let m = Move { from, to, amount };
+            prop_assert_eq!(m.to_string().parse::<Move>().unwrap(), m);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use common::{ParseError, Solution};
+
+use std::collections::HashSet;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["7", "19"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    get_start_of_packet_sequence_index(input, 4).unwrap()
+}
+
+pub fn part2(input: &str) -> usize {
+    get_start_of_packet_sequence_index(input, 14).unwrap()
+}
+
+fn get_start_of_packet_sequence_index(s: &str, window_size: usize) -> Result<usize, ()> {
+    let chars = s.chars().collect::<Vec<char>>();
+    for (i, window) in chars.windows(window_size).enumerate() {
+        let unique_chars = window.iter().copied().collect::<HashSet<char>>().len();
+        if unique_chars == window_size {
+            return Ok(i + window_size);
+        }
+    }
+    Err(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day6::parse(Day6::EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&input).to_string(), Day6::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day6::parse(Day6::EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&input).to_string(), Day6::EXAMPLE_ANSWERS[1]);
+    }
+}
@@ -0,0 +1,102 @@
+use common::Generated;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+struct Node {
+    name: String,
+    files: Vec<(String, usize)>,
+    children: Vec<usize>,
+}
+
+// a tree of `size` directories, explored depth first with `cd` and `ls`
+pub fn generate(seed: u64, size: usize) -> Generated {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    let mut nodes = vec![Node {
+        name: "/".to_string(),
+        files: vec![],
+        children: vec![],
+    }];
+    for _ in 1..size {
+        let parent = rng.gen_range(0..nodes.len());
+        // directories are looked up by name, so siblings need different ones
+        let name = loop {
+            let name = word(&mut rng);
+            if nodes[parent]
+                .children
+                .iter()
+                .all(|&c| nodes[c].name != name)
+            {
+                break name;
+            }
+        };
+        nodes.push(Node {
+            name,
+            files: vec![],
+            children: vec![],
+        });
+        let child = nodes.len() - 1;
+        nodes[parent].children.push(child);
+    }
+
+    // keep the files small enough that the whole tree stays under 30000000
+    let largest = (30_000_000 / (size * 9)).max(1);
+    for node in nodes.iter_mut() {
+        for _ in 0..rng.gen_range(0..10) {
+            let name = format!("{}.{}", word(&mut rng), &word(&mut rng)[..3]);
+            node.files.push((name, rng.gen_range(1..=largest)));
+        }
+    }
+    // then fill the disk past 40000000 so there's always something to delete
+    let used = total_size(&nodes, 0);
+    let filler = 40_000_000usize.saturating_sub(used) + rng.gen_range(1..1_000_000);
+    nodes[0].files.push(("filler.dat".to_string(), filler));
+
+    let mut input = "$ cd /\n".to_string();
+    transcript(&nodes, 0, &mut input);
+
+    let sizes = (0..nodes.len())
+        .map(|i| total_size(&nodes, i))
+        .collect::<Vec<_>>();
+    let part1: usize = sizes.iter().filter(|&&s| s <= 100_000).sum();
+    let needed = 30_000_000 - (70_000_000 - sizes[0]);
+    let part2 = sizes.iter().filter(|&&s| s >= needed).min().unwrap();
+
+    Generated {
+        input,
+        answers: [part1.to_string(), part2.to_string()],
+    }
+}
+
+fn word(rng: &mut StdRng) -> String {
+    (0..rng.gen_range(3..=8))
+        .map(|_| rng.gen_range(b'a'..=b'z') as char)
+        .collect()
+}
+
+fn total_size(nodes: &[Node], index: usize) -> usize {
+    let node = &nodes[index];
+    node.files.iter().map(|(_, size)| size).sum::<usize>()
+        + node
+            .children
+            .iter()
+            .map(|&c| total_size(nodes, c))
+            .sum::<usize>()
+}
+
+fn transcript(nodes: &[Node], index: usize, out: &mut String) {
+    let node = &nodes[index];
+    out.push_str("$ ls\n");
+    for &child in &node.children {
+        out.push_str(&format!("dir {}\n", nodes[child].name));
+    }
+    for (name, size) in &node.files {
+        out.push_str(&format!("{} {}\n", size, name));
+    }
+    for &child in &node.children {
+        out.push_str(&format!("$ cd {}\n", nodes[child].name));
+        transcript(nodes, child, out);
+        out.push_str("$ cd ..\n");
+    }
+}
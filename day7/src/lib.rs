@@ -0,0 +1,442 @@
+use common::{parse_token, Generated, ParseError, Solution};
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+const DAY: u8 = 7;
+
+mod generate;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = FileSystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["95437", "24933642"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_filesystem(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<Generated> {
+        Some(generate::generate(seed, size))
+    }
+}
+
+pub fn parse_filesystem(input: &str) -> Result<FileSystem, ParseError> {
+    let mut offset = 0;
+    let commands: Vec<CommandExecution> = input
+        .split("\n$ ")
+        .map(|s| {
+            let command = s.parse().map_err(|e: ParseError| e.offset_lines(offset));
+            offset += s.lines().count();
+            command
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut fs = FileSystem::new();
+    fs.parse_command_executions(commands);
+    Ok(fs)
+}
+
+pub fn part1(fs: &FileSystem) -> usize {
+    fs.get_all_flattened_directories()
+        .iter()
+        .filter(|&d| d.get_size() <= 100000)
+        .map(|d| d.get_size())
+        .sum::<usize>()
+}
+
+pub fn part2(fs: &FileSystem) -> usize {
+    let dirs = fs.get_all_flattened_directories();
+    let used = dirs[0].get_size();
+    let disk_space_needed = 30000000usize.saturating_sub(70000000usize.saturating_sub(used));
+    // there's already room for the update, nothing needs deleting
+    if disk_space_needed == 0 {
+        return 0;
+    }
+
+    // the root always frees enough, but a full disk is the only reason to fall back to it
+    dirs.iter()
+        .map(|d| d.get_size())
+        .filter(|&size| size >= disk_space_needed)
+        .min()
+        .unwrap_or(used)
+}
+
+struct Directory {
+    name: String,
+    files: Vec<File>,
+    directories: HashMap<String, Directory>,
+}
+
+impl Directory {
+    fn new(name: String) -> Directory {
+        Directory {
+            name,
+            files: vec![],
+            directories: HashMap::new(),
+        }
+    }
+
+    fn get_size(&self) -> usize {
+        self.files.iter().map(|f| f.size).sum::<usize>()
+            + self
+                .directories
+                .values()
+                .map(|d| d.get_size())
+                .sum::<usize>()
+    }
+
+    fn get_all_directories(&self) -> Vec<&Directory> {
+        let mut dirs = vec![self];
+        for (_, d) in self.directories.iter() {
+            dirs.extend(d.get_all_directories());
+        }
+        dirs
+    }
+}
+
+impl fmt::Debug for Directory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Directory {{ name: {}, total_size: {:?} files: {:#?}, directories: {:#?} }}",
+            self.name,
+            self.get_size(),
+            self.files,
+            self.directories
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct FileSystem {
+    directories: HashMap<String, Directory>,
+    current_dir_stack: Vec<String>,
+}
+
+impl FileSystem {
+    fn new() -> FileSystem {
+        let mut fs = FileSystem {
+            directories: HashMap::new(),
+            current_dir_stack: vec!["/".to_string()],
+        };
+
+        fs.directories
+            .insert("/".to_string(), Directory::new("/".to_string()));
+
+        fs
+    }
+
+    fn get_current_directory(&mut self) -> &mut Directory {
+        let mut cwd = self
+            .directories
+            .get_mut(self.current_dir_stack.first().unwrap())
+            .unwrap();
+        for p in self.current_dir_stack.iter().skip(1) {
+            cwd = cwd.directories.get_mut(p).unwrap();
+        }
+        cwd
+    }
+
+    fn get_all_flattened_directories(&self) -> Vec<&Directory> {
+        let mut dirs = vec![];
+        for (_, d) in self.directories.iter() {
+            dirs.extend(d.get_all_directories());
+        }
+        dirs
+    }
+
+    fn parse_command_executions(&mut self, command_executions: Vec<CommandExecution>) {
+        for c in command_executions.into_iter() {
+            match c.command.binary {
+                Binary::ChangeDirectory(s) => match s.as_str() {
+                    "/" => {
+                        self.current_dir_stack.truncate(1);
+                    }
+                    ".." => {
+                        // the root is always at the bottom of the stack
+                        if self.current_dir_stack.len() > 1 {
+                            self.current_dir_stack.pop();
+                        }
+                    }
+                    "." => {}
+                    _ => {
+                        // allow moving into directories that haven't been listed yet
+                        self.get_current_directory()
+                            .directories
+                            .entry(s.clone())
+                            .or_insert_with(|| Directory::new(s.clone()));
+                        self.current_dir_stack.push(s);
+                    }
+                },
+                Binary::List => {
+                    let cwd = self.get_current_directory();
+                    for entry in c.stdout {
+                        match entry {
+                            Entry::Directory(name) => {
+                                cwd.directories.insert(name.clone(), Directory::new(name));
+                            }
+                            Entry::File(file) => {
+                                cwd.files.push(file);
+                            }
+                        }
+                    }
+                }
+
+                _ => {}
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct File {
+    name: String,
+    size: usize,
+}
+
+impl FromStr for File {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((size, name)) = s.split_once(' ') else {
+            return Err(ParseError::missing(DAY, s, "expected `<size> <name>`"));
+        };
+        let size = parse_token(DAY, s, size)?;
+        let name = name.to_string();
+        Ok(File { name, size })
+    }
+}
+
+impl fmt::Display for File {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.size, self.name)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Entry {
+    Directory(String),
+    File(File),
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("dir ") {
+            Some(name) => Ok(Entry::Directory(name.to_string())),
+            None => Ok(Entry::File(s.parse()?)),
+        }
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Entry::Directory(name) => write!(f, "dir {}", name),
+            Entry::File(file) => write!(f, "{}", file),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Binary {
+    Unknown(String),
+
+    ChangeDirectory(String),
+    List,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Command {
+    binary: Binary,
+    arguments: Vec<String>,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let Some(command) = parts.next() else {
+            return Err(ParseError::missing(DAY, s, "expected a command"));
+        };
+        let arguments = parts.map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let binary = match command {
+            "cd" => match arguments.first() {
+                Some(directory) => Binary::ChangeDirectory(directory.clone()),
+                None => {
+                    return Err(ParseError::missing(
+                        DAY,
+                        s,
+                        "expected a directory to cd into",
+                    ))
+                }
+            },
+            "ls" => Binary::List,
+            _ => Binary::Unknown(command.to_string()),
+        };
+
+        Ok(Command { binary, arguments })
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.binary {
+            Binary::ChangeDirectory(_) => write!(f, "cd")?,
+            Binary::List => write!(f, "ls")?,
+            Binary::Unknown(name) => write!(f, "{}", name)?,
+        }
+        for argument in &self.arguments {
+            write!(f, " {}", argument)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct CommandExecution {
+    command: Command,
+    stdout: Vec<Entry>,
+}
+
+impl FromStr for CommandExecution {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim_end();
+        let line = trimmed.lines().next().unwrap_or_default();
+        let command = line.strip_prefix("$ ").unwrap_or(line);
+        Ok(CommandExecution {
+            // errors in the command are relative to the text after the prompt
+            command: command.parse().map_err(|e: ParseError| ParseError {
+                text: line.to_string(),
+                column: e.column + line.len() - command.len(),
+                ..e
+            })?,
+            stdout: trimmed
+                .lines()
+                .enumerate()
+                .skip(1)
+                .map(|(i, s)| s.parse().map_err(|e: ParseError| e.offset_lines(i)))
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl fmt::Display for CommandExecution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "$ {}", self.command)?;
+        for entry in &self.stdout {
+            writeln!(f, "{}", entry)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day7::parse(Day7::EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input).to_string(), Day7::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7::parse(Day7::EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input).to_string(), Day7::EXAMPLE_ANSWERS[1]);
+    }
+
+    fn name() -> impl Strategy<Value = String> {
+        "[a-z]{1,8}(\\.[a-z]{1,3})?"
+    }
+
+    fn entry() -> impl Strategy<Value = Entry> {
+        prop_oneof![
+            name().prop_map(Entry::Directory),
+            (any::<usize>(), name()).prop_map(|(size, name)| Entry::File(File { name, size })),
+        ]
+    }
+
+    fn execution() -> impl Strategy<Value = CommandExecution> {
+        let cd = name().prop_map(|directory| CommandExecution {
+            command: Command {
+                binary: Binary::ChangeDirectory(directory.clone()),
+                arguments: vec![directory],
+            },
+            stdout: vec![],
+        });
+        let ls = prop::collection::vec(entry(), 0..8).prop_map(|stdout| CommandExecution {
+            command: Command {
+                binary: Binary::List,
+                arguments: vec![],
+            },
+            stdout,
+        });
+        let unknown = (
+            "[a-z]{1,5}".prop_filter("not a known command", |c| c != "cd" && c != "ls"),
+            prop::collection::vec(name(), 0..3),
+        )
+            .prop_map(|(command, arguments)| CommandExecution {
+                command: Command {
+                    binary: Binary::Unknown(command),
+                    arguments,
+                },
+                stdout: vec![],
+            });
+        prop_oneof![cd, ls, unknown]
+    }
+
+    proptest! {
+        #[test]
+        fn execution_round_trip(execution in execution()) {
+            prop_assert_eq!(execution.to_string().parse::<CommandExecution>().unwrap(), execution);
+        }
+    }
+
+    #[test]
+    fn exact_sizes_count() {
+        // `a` is exactly at the part 1 limit and frees exactly the space needed
+        let input = "$ cd /\n$ ls\ndir a\n40000000 big\n$ cd a\n$ ls\n100000 small\n";
+        let fs = Day7::parse(input).unwrap();
+        assert_eq!(Day7::part1(&fs), 100000);
+        assert_eq!(Day7::part2(&fs), 100000);
+    }
+
+    #[test]
+    fn room_to_spare() {
+        let fs = Day7::parse("$ cd /\n$ ls\n100 a\n").unwrap();
+        assert_eq!(Day7::part2(&fs), 0);
+    }
+
+    #[test]
+    fn generated_answers() {
+        for seed in 0..10 {
+            let generated = Day7::generate(seed, 200).unwrap();
+            let input = Day7::parse(&generated.input).unwrap();
+            assert_eq!(Day7::part1(&input).to_string(), generated.answers[0]);
+            assert_eq!(Day7::part2(&input).to_string(), generated.answers[1]);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use common::Generated;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// a `size` by `size` forest, checked by looking in from every edge and walking out from
+// every tree
+pub fn generate(seed: u64, size: usize) -> Generated {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    let trees = (0..size)
+        .map(|_| (0..size).map(|_| rng.gen_range(0..=9)).collect::<Vec<u8>>())
+        .collect::<Vec<_>>();
+
+    let mut input = String::new();
+    for row in &trees {
+        input.extend(row.iter().map(|&t| (b'0' + t) as char));
+        input.push('\n');
+    }
+
+    Generated {
+        input,
+        answers: [visible(&trees).to_string(), best_score(&trees).to_string()],
+    }
+}
+
+const DIRECTIONS: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+// the positions from the edge inwards, for each line of sight into the forest
+fn sight_lines(size: usize) -> Vec<Vec<(usize, usize)>> {
+    let mut lines = vec![];
+    for i in 0..size {
+        lines.push((0..size).map(|x| (x, i)).collect::<Vec<_>>());
+        lines.push((0..size).rev().map(|x| (x, i)).collect());
+        lines.push((0..size).map(|y| (i, y)).collect());
+        lines.push((0..size).rev().map(|y| (i, y)).collect());
+    }
+    lines
+}
+
+fn visible(trees: &[Vec<u8>]) -> usize {
+    let size = trees.len();
+    let mut seen = vec![vec![false; size]; size];
+    for line in sight_lines(size) {
+        let mut tallest = None;
+        for (x, y) in line {
+            if tallest.is_none_or(|t| trees[y][x] > t) {
+                seen[y][x] = true;
+                tallest = Some(trees[y][x]);
+            }
+        }
+    }
+    seen.iter().flatten().filter(|&&s| s).count()
+}
+
+fn best_score(trees: &[Vec<u8>]) -> usize {
+    let size = trees.len() as isize;
+    let mut best = 0;
+    for y in 0..size {
+        for x in 0..size {
+            let height = trees[y as usize][x as usize];
+            let mut score = 1;
+            for (dx, dy) in DIRECTIONS {
+                let (mut cx, mut cy) = (x + dx, y + dy);
+                let mut distance = 0;
+                while (0..size).contains(&cx) && (0..size).contains(&cy) {
+                    distance += 1;
+                    if trees[cy as usize][cx as usize] >= height {
+                        break;
+                    }
+                    cx += dx;
+                    cy += dy;
+                }
+                score *= distance;
+            }
+            best = best.max(score);
+        }
+    }
+    best
+}
@@ -0,0 +1,180 @@
+use common::{Generated, ParseError, Solution};
+use grid::{Grid, GridError};
+
+const DAY: u8 = 8;
+
+mod generate;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = Grid<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["21", "8"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_trees(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part_2(input)
+    }
+
+    fn generate(seed: u64, size: usize) -> Option<Generated> {
+        Some(generate::generate(seed, size))
+    }
+}
+
+pub fn parse_trees(input: &str) -> Result<Grid<u32>, ParseError> {
+    let rows = input
+        .lines()
+        .enumerate()
+        .map(|(y, l)| {
+            l.chars()
+                .enumerate()
+                .map(|(x, c)| {
+                    c.to_digit(10).ok_or_else(|| {
+                        ParseError::new(DAY, l, x + 1, format!("`{}` is not a tree height", c))
+                            .offset_lines(y)
+                    })
+                })
+                .collect::<Result<Vec<u32>, _>>()
+        })
+        .collect::<Result<Vec<Vec<u32>>, _>>()?;
+
+    Grid::from_rows(rows).map_err(|e| match e {
+        GridError::RaggedRow { row, len, expected } => {
+            let line = input.lines().nth(row).unwrap_or_default();
+            ParseError::new(DAY, line, len.min(expected) + 1, e.to_string()).offset_lines(row)
+        }
+    })
+}
+
+pub fn part_1(trees: &Grid<u32>) -> usize {
+    if trees.width() < 3 || trees.height() < 3 {
+        // every tree is on the edge
+        return trees.width() * trees.height();
+    }
+
+    // the count starts with the perimeter of the grid
+    let mut count = (trees.width() + trees.height() - 2) * 2;
+    for y in 1..trees.height() - 1 {
+        for x in 1..trees.width() - 1 {
+            if tree_is_visible(trees, x, y) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+pub fn part_2(trees: &Grid<u32>) -> usize {
+    // find the max tree score, trees on the edge always score 0
+    let mut max_score = 0;
+    for y in 1..trees.height().saturating_sub(1) {
+        for x in 1..trees.width().saturating_sub(1) {
+            let score = get_tree_score(trees, x, y);
+            if score > max_score {
+                max_score = score;
+            }
+        }
+    }
+    max_score
+}
+
+fn get_tree_score(trees: &Grid<u32>, x: usize, y: usize) -> usize {
+    let trees_in_all_directions = trees_in_all_directions(trees, x, y);
+    let tree_height = trees[(x, y)];
+
+    trees_in_all_directions
+        .iter()
+        .map(|direction| {
+            direction
+                .iter()
+                .position(|t| t >= &tree_height)
+                .map(|t| t + 1)
+                .unwrap_or_else(|| direction.len())
+        })
+        .product()
+}
+
+fn tree_is_visible(trees: &Grid<u32>, x: usize, y: usize) -> bool {
+    let trees_in_all_directions = trees_in_all_directions(trees, x, y);
+    let tree_height = trees[(x, y)];
+
+    // a tree is visible if there is no tree in any direction that is taller than it
+    // if any of the directions have a tree that is taller than the current tree
+    // then the current tree is not visible
+
+    trees_in_all_directions
+        .iter()
+        .any(|direction| direction.iter().all(|t| t < &tree_height))
+}
+
+fn trees_in_all_directions(trees: &Grid<u32>, x: usize, y: usize) -> [Vec<u32>; 4] {
+    // for each direction (up, down, left, right)
+    // collect the vector of trees in that direction, not including the current tree
+
+    // get the current row and column of the grid position so we can split them
+    let row = trees.row(y);
+    let column = trees.column(x).copied().collect::<Vec<u32>>();
+
+    let (above, below) = column.split_at(y);
+    let (left, right) = row.split_at(x);
+
+    [
+        above.iter().copied().rev().collect(),
+        below[1..].to_vec(),
+        left.iter().copied().rev().collect(),
+        right[1..].to_vec(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day8::parse(Day8::EXAMPLE).unwrap();
+        assert_eq!(Day8::part1(&input).to_string(), Day8::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day8::parse(Day8::EXAMPLE).unwrap();
+        assert_eq!(Day8::part2(&input).to_string(), Day8::EXAMPLE_ANSWERS[1]);
+    }
+
+    fn trees() -> impl Strategy<Value = Grid<u32>> {
+        (1..10usize, 1..10usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(prop::collection::vec(0..10u32, width), height)
+                .prop_map(|rows| Grid::from_rows(rows).unwrap())
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn trees_round_trip(trees in trees()) {
+            prop_assert_eq!(parse_trees(&trees.to_string()).unwrap(), trees);
+        }
+    }
+
+    #[test]
+    fn generated_answers() {
+        for seed in 0..10 {
+            let generated = Day8::generate(seed, 50).unwrap();
+            let input = Day8::parse(&generated.input).unwrap();
+            assert_eq!(Day8::part1(&input).to_string(), generated.answers[0]);
+            assert_eq!(Day8::part2(&input).to_string(), generated.answers[1]);
+        }
+    }
+}
@@ -0,0 +1,351 @@
+use common::{parse_token, Frame, ParseError, Simulation, Solution};
+use grid::{Point, SparseGrid};
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+const DAY: u8 = 9;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = include_str!("example");
+    const EXAMPLE_ANSWERS: [&'static str; 2] = ["13", "1"];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn simulate(input: &Self::Input<'_>, part: u8) -> Option<Box<dyn Simulation>> {
+        let knots = if part == 1 { 2 } else { 10 };
+        Some(Box::new(RopeSimulation::new(input.clone(), knots)))
+    }
+}
+
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| l.parse().map_err(|e: ParseError| e.offset_lines(i)))
+        .collect::<Result<Vec<_>, _>>()
+}
+
+pub fn part1(input: &[Instruction]) -> usize {
+    let mut rope = Rope::new(2);
+    for inst in input {
+        for _ in 0..inst.distance {
+            rope.pull(inst.direction);
+        }
+    }
+    rope.tail_positions.len()
+}
+
+pub fn part2(input: &[Instruction]) -> usize {
+    let mut rope = Rope::new(10);
+    for inst in input {
+        for _ in 0..inst.distance {
+            rope.pull(inst.direction);
+        }
+    }
+    rope.tail_positions.len()
+}
+
+struct Rope {
+    knots: Vec<Vector>,
+    // each position is only stored once
+    tail_positions: SparseGrid<()>,
+}
+
+impl Rope {
+    fn new(length: usize) -> Rope {
+        let mut tail_positions = SparseGrid::new();
+        tail_positions.insert((0, 0), ());
+        Rope {
+            knots: vec![Vector { x: 0, y: 0 }; length],
+            tail_positions,
+        }
+    }
+
+    // moves the head one step, with the rest of the knots following on
+    fn pull(&mut self, direction: Direction) {
+        let knots = &mut self.knots;
+        knots[0] = knots[0].step_direction(&direction);
+        for i in 1..knots.len() {
+            while knots[i].distance_from(&knots[i - 1]) > 1 {
+                // we want to move in the direction of the knot in front
+                let direction = knots[i].direction_to(&knots[i - 1]);
+                knots[i] = knots[i].add(&direction);
+            }
+        }
+        // get the position of the last knot and store it
+        let tail = knots[knots.len() - 1];
+        self.tail_positions.insert(tail.point(), ());
+    }
+}
+
+/// The rope being pulled through the instructions a step at a time
+pub struct RopeSimulation {
+    rope: Rope,
+    instructions: Vec<Instruction>,
+    current: usize,
+    moved: i32,
+}
+
+impl RopeSimulation {
+    pub fn new(instructions: Vec<Instruction>, knots: usize) -> RopeSimulation {
+        RopeSimulation {
+            rope: Rope::new(knots),
+            instructions,
+            current: 0,
+            moved: 0,
+        }
+    }
+}
+
+// how much of the rope's surroundings are drawn
+const VIEW_WIDTH: i64 = 80;
+const VIEW_HEIGHT: i64 = 30;
+
+impl Simulation for RopeSimulation {
+    fn frame(&self) -> Frame {
+        // drawn with up at the top, so y is flipped
+        let mut picture: SparseGrid<char> = self
+            .rope
+            .tail_positions
+            .iter()
+            .map(|((x, y), _)| ((x, -y), '#'))
+            .collect();
+        picture.insert((0, 0), 's');
+        // the knots nearer the head are drawn over the ones behind them
+        for (i, knot) in self.rope.knots.iter().enumerate().rev() {
+            let c = match i {
+                0 => 'H',
+                _ if self.rope.knots.len() == 2 => 'T',
+                _ => (b'0' + i as u8) as char,
+            };
+            picture.insert((knot.x as i64, -knot.y as i64), c);
+        }
+
+        // follow the head once everything doesn't fit
+        let ((mut min_x, mut min_y), (mut max_x, mut max_y)) = picture.bounds().unwrap();
+        let head = self.rope.knots[0];
+        if max_x - min_x >= VIEW_WIDTH {
+            min_x = head.x as i64 - VIEW_WIDTH / 2;
+            max_x = min_x + VIEW_WIDTH - 1;
+        }
+        if max_y - min_y >= VIEW_HEIGHT {
+            min_y = -head.y as i64 - VIEW_HEIGHT / 2;
+            max_y = min_y + VIEW_HEIGHT - 1;
+        }
+
+        let instruction = match self.instructions.get(self.current) {
+            Some(inst) => format!("{} ({} of {})", inst, self.moved, inst.distance),
+            None => "done".to_string(),
+        };
+        Frame {
+            caption: format!(
+                "{}, instruction {} of {}, the tail has visited {} positions",
+                instruction,
+                (self.current + 1).min(self.instructions.len()),
+                self.instructions.len(),
+                self.rope.tail_positions.len()
+            ),
+            picture: picture.render_area((min_x, min_y), (max_x, max_y), |c| *c.unwrap_or(&'.')),
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        // skip over anything that doesn't move the rope
+        while let Some(inst) = self.instructions.get(self.current) {
+            if self.moved < inst.distance {
+                self.rope.pull(inst.direction);
+                self.moved += 1;
+                return true;
+            }
+            self.current += 1;
+            self.moved = 0;
+        }
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Vector {
+    x: i32,
+    y: i32,
+}
+
+impl Vector {
+    fn point(&self) -> Point {
+        (self.x as i64, self.y as i64)
+    }
+
+    fn step_direction(&self, direction: &Direction) -> Vector {
+        match direction {
+            Direction::Up => Vector {
+                x: self.x,
+                y: self.y + 1,
+            },
+            Direction::Down => Vector {
+                x: self.x,
+                y: self.y - 1,
+            },
+            Direction::Left => Vector {
+                x: self.x - 1,
+                y: self.y,
+            },
+            Direction::Right => Vector {
+                x: self.x + 1,
+                y: self.y,
+            },
+        }
+    }
+
+    fn add(&self, other: &Vector) -> Vector {
+        Vector {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+
+    fn direction_to(&self, other: &Vector) -> Vector {
+        let x = match self.x.cmp(&other.x) {
+            std::cmp::Ordering::Less => 1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => -1,
+        };
+        let y = match self.y.cmp(&other.y) {
+            std::cmp::Ordering::Less => 1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => -1,
+        };
+        Vector { x, y }
+    }
+
+    fn distance_from(&self, other: &Vector) -> i32 {
+        // d=√((x2 – x1)² + (y2 – y1)²)
+        let x = (other.x - self.x).pow(2);
+        let y = (other.y - self.y).pow(2);
+        ((x + y) as f32).sqrt() as i32
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    direction: Direction,
+    distance: i32,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((direction, distance)) = s.split_once(' ') else {
+            return Err(ParseError::missing(
+                DAY,
+                s,
+                "expected `<direction> <distance>`",
+            ));
+        };
+        let direction = match direction {
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            _ => {
+                return Err(ParseError::at(
+                    DAY,
+                    s,
+                    direction,
+                    format!("unknown direction `{}`", direction),
+                ))
+            }
+        };
+        let distance = parse_token(DAY, s, distance.trim())?;
+        Ok(Instruction {
+            direction,
+            distance,
+        })
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            Direction::Up => "U",
+            Direction::Down => "D",
+            Direction::Left => "L",
+            Direction::Right => "R",
+        };
+        write!(f, "{} {}", direction, self.distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day9::parse(Day9::EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input).to_string(), Day9::EXAMPLE_ANSWERS[0]);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9::parse(Day9::EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input).to_string(), Day9::EXAMPLE_ANSWERS[1]);
+    }
+
+    #[test]
+    fn simulation_visits_every_tail_position() {
+        let input = Day9::parse(Day9::EXAMPLE).unwrap();
+        let mut simulation = RopeSimulation::new(input, 2);
+        while simulation.step() {}
+        assert!(simulation
+            .frame()
+            .caption
+            .ends_with(&format!("{} positions", Day9::EXAMPLE_ANSWERS[0])));
+    }
+
+    fn instruction() -> impl Strategy<Value = Instruction> {
+        let direction = prop_oneof![
+            Just(Direction::Up),
+            Just(Direction::Down),
+            Just(Direction::Left),
+            Just(Direction::Right),
+        ];
+        (direction, any::<i32>()).prop_map(|(direction, distance)| Instruction {
+            direction,
+            distance,
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn instruction_round_trip(instruction in instruction()) {
+            prop_assert_eq!(instruction.to_string().parse::<Instruction>().unwrap(), instruction);
+        }
+    }
+}
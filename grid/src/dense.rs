@@ -0,0 +1,274 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// An `(x, y)` position in a [`Grid`], with `(0, 0)` in the top left
+pub type Position = (usize, usize);
+
+/// A fixed size rectangular grid, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// a row was a different length to the first one, `row` counts from 0
+    RaggedRow {
+        row: usize,
+        len: usize,
+        expected: usize,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::RaggedRow { expected, .. } => {
+                write!(f, "expected {} squares like the first row", expected)
+            }
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which all have to be the same length
+    pub fn from_rows<R>(rows: impl IntoIterator<Item = R>) -> Result<Grid<T>, GridError>
+    where
+        R: IntoIterator<Item = T>,
+    {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for (y, row) in rows.into_iter().enumerate() {
+            let before = cells.len();
+            cells.extend(row);
+            let len = cells.len() - before;
+            if y == 0 {
+                width = len;
+            } else if len != width {
+                return Err(GridError::RaggedRow {
+                    row: y,
+                    len,
+                    expected: width,
+                });
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.offset(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let offset = self.offset(position);
+            Some(&mut self.cells[offset])
+        } else {
+            None
+        }
+    }
+
+    /// Every position in the grid, a row at a time
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |i| (i % width, i / width))
+    }
+
+    /// Every cell along with its position, a row at a time
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // chunks panics on 0, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The positions next to this one that are inside the grid, optionally including diagonals
+    pub fn neighbours(
+        &self,
+        (x, y): Position,
+        diagonals: bool,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let offsets: &[(isize, isize)] = if diagonals {
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ]
+        } else {
+            &[(0, -1), (-1, 0), (1, 0), (0, 1)]
+        };
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(position).then_some(position)
+        })
+    }
+
+    /// Draws the grid with a character for each cell, a line per row
+    pub fn render(&self, draw: impl Fn(&T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            out.extend(row.iter().map(&draw));
+            out.push('\n');
+        }
+        out
+    }
+
+    fn offset(&self, (x, y): Position) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        assert!(
+            self.contains(position),
+            "{:?} is outside the {}x{} grid",
+            position,
+            self.width,
+            self.height
+        );
+        &self.cells[self.offset(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        assert!(
+            self.contains(position),
+            "{:?} is outside the {}x{} grid",
+            position,
+            self.width,
+            self.height
+        );
+        let offset = self.offset(position);
+        &mut self.cells[offset]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u8> {
+        Grid::from_rows([[1, 2, 3], [4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn rows_must_be_the_same_length() {
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3]]),
+            Err(GridError::RaggedRow {
+                row: 1,
+                len: 1,
+                expected: 2
+            })
+        );
+    }
+
+    #[test]
+    fn stored_a_row_at_a_time() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(
+            grid.positions().collect::<Vec<_>>(),
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = digits();
+        assert_eq!(
+            grid.neighbours((0, 0), false).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours((0, 0), true).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(grid.neighbours((1, 1), true).count(), 5);
+    }
+
+    #[test]
+    fn renders_a_line_per_row() {
+        let grid = digits();
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(
+            grid.render(|&d| if d % 2 == 0 { '#' } else { '.' }),
+            ".#.\n#.#\n"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod dense;
+mod sparse;
+
+pub use dense::{Grid, GridError, Position};
+pub use sparse::{Point, SparseGrid};
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+/// An `(x, y)` point in a [`SparseGrid`], which can be anywhere including negative
+pub type Point = (i64, i64);
+
+/// An unbounded grid that only stores the cells that have been set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    /// Sets a cell, giving back what was there before
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// How many cells have been set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The set cells, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&p, v)| (p, v))
+    }
+
+    /// The smallest and largest corners of the box around every set cell
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), &(x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        }))
+    }
+
+    /// The points around this one, optionally including diagonals
+    pub fn neighbours((x, y): Point, diagonals: bool) -> impl Iterator<Item = Point> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(move |&(dx, dy)| (dx, dy) != (0, 0) && (diagonals || dx == 0 || dy == 0))
+            .map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    /// Draws the box around every set cell, with the smallest y on the first line
+    pub fn render(&self, draw: impl Fn(Option<&T>) -> char) -> String {
+        match self.bounds() {
+            Some((min, max)) => self.render_area(min, max, draw),
+            None => String::new(),
+        }
+    }
+
+    /// Draws the cells from one corner to the other, set or not
+    pub fn render_area(
+        &self,
+        (min_x, min_y): Point,
+        (max_x, max_y): Point,
+        draw: impl Fn(Option<&T>) -> char,
+    ) -> String {
+        let mut out = String::new();
+        for y in min_y..=max_y {
+            out.extend((min_x..=max_x).map(|x| draw(self.get((x, y)))));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grows_in_every_direction() {
+        let mut grid = SparseGrid::new();
+        grid.insert((0, 0), 'H');
+        grid.insert((-2, 1), 'T');
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some(((-2, 0), (0, 1))));
+        assert_eq!(grid.render(|c| *c.unwrap_or(&'.')), "..H\nT..\n");
+    }
+
+    #[test]
+    fn neighbours() {
+        assert_eq!(SparseGrid::<()>::neighbours((0, 0), false).count(), 4);
+        assert_eq!(SparseGrid::<()>::neighbours((0, 0), true).count(), 8);
+        assert!(SparseGrid::<()>::neighbours((5, -5), false)
+            .all(|(x, y)| (x - 5).abs() + (y + 5).abs() == 1));
+    }
+}
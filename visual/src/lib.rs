@@ -0,0 +1,5 @@
+mod player;
+mod recording;
+
+pub use player::{play, Options};
+pub use recording::{record, Recording};
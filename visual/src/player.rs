@@ -0,0 +1,143 @@
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+use common::{Frame, Simulation};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, queue, terminal};
+
+/// How a simulation is played
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    pub frames_per_second: f64,
+    /// start paused, stepping a frame at a time
+    pub paused: bool,
+}
+
+/// Plays a simulation in the terminal. Space pauses, `n` or right steps while paused, `+` and
+/// `-` change the speed and `q` quits. When stdout isn't a terminal every frame is just printed.
+pub fn play(simulation: &mut dyn Simulation, options: Options) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    if !stdout.is_terminal() {
+        loop {
+            let frame = simulation.frame();
+            writeln!(stdout, "{}\n{}", frame.caption, frame.picture)?;
+            if !simulation.step() {
+                return Ok(());
+            }
+        }
+    }
+
+    let _screen = Screen::enter()?;
+    let mut delay = Duration::from_secs_f64(1.0 / options.frames_per_second.max(0.01));
+    let mut paused = options.paused;
+    let mut finished = false;
+    let mut count = 1;
+    loop {
+        draw(
+            &mut stdout,
+            &simulation.frame(),
+            count,
+            delay,
+            paused,
+            finished,
+        )?;
+
+        // wait for the next frame to be due, or for a key
+        let due = Instant::now() + delay;
+        let advance = loop {
+            let waiting = paused || finished;
+            let timeout = if waiting {
+                Duration::from_secs(60)
+            } else {
+                due.saturating_duration_since(Instant::now())
+            };
+            if !event::poll(timeout)? {
+                if waiting {
+                    continue;
+                }
+                break true;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Ok(())
+                }
+                KeyCode::Char(' ') => paused = !paused,
+                KeyCode::Char('n') | KeyCode::Right if paused => break true,
+                KeyCode::Char('+') | KeyCode::Char('=') => delay /= 2,
+                KeyCode::Char('-') => delay *= 2,
+                _ => continue,
+            }
+            // redraw to show the change
+            break false;
+        };
+
+        if advance && !finished {
+            if simulation.step() {
+                count += 1;
+            } else {
+                finished = true;
+            }
+        }
+    }
+}
+
+fn draw(
+    out: &mut impl Write,
+    frame: &Frame,
+    count: usize,
+    delay: Duration,
+    paused: bool,
+    finished: bool,
+) -> io::Result<()> {
+    queue!(
+        out,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(terminal::ClearType::All)
+    )?;
+    // raw mode doesn't go back to the start of the line by itself
+    write!(out, "{}\r\n\r\n", frame.caption)?;
+    for line in frame.picture.lines() {
+        write!(out, "{}\r\n", line)?;
+    }
+
+    let state = if finished {
+        "finished"
+    } else if paused {
+        "paused"
+    } else {
+        "playing"
+    };
+    write!(
+        out,
+        "\r\nframe {} · {} · {:.1} fps · space pause · n step · +/- speed · q quit",
+        count,
+        state,
+        1.0 / delay.as_secs_f64()
+    )?;
+    out.flush()
+}
+
+// puts the terminal back how it was however playing ends
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
@@ -0,0 +1,133 @@
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+
+use common::{Frame, Simulation};
+use serde::{Deserialize, Serialize};
+
+// frames are saved a line of JSON each
+#[derive(Serialize, Deserialize)]
+struct Line {
+    caption: String,
+    picture: String,
+}
+
+/// Runs a simulation to the end, writing out every frame. Returns how many there were.
+pub fn record(simulation: &mut dyn Simulation, out: &mut impl Write) -> io::Result<usize> {
+    let mut count = 0;
+    loop {
+        let Frame { caption, picture } = simulation.frame();
+        serde_json::to_writer(&mut *out, &Line { caption, picture })?;
+        writeln!(out)?;
+        count += 1;
+        if !simulation.step() {
+            return Ok(count);
+        }
+    }
+}
+
+/// Frames saved by [`record`], which can be played back like the simulation they came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recording {
+    frames: Vec<Frame>,
+    current: usize,
+}
+
+impl Recording {
+    pub fn load(path: &Path) -> io::Result<Recording> {
+        Recording::read(BufReader::new(fs::File::open(path)?))
+    }
+
+    pub fn read(reader: impl BufRead) -> io::Result<Recording> {
+        let mut frames = vec![];
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let Line { caption, picture } = serde_json::from_str(&line).map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", i + 1, e))
+            })?;
+            frames.push(Frame { caption, picture });
+        }
+        if frames.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the recording has no frames",
+            ));
+        }
+        Ok(Recording { frames, current: 0 })
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+impl Simulation for Recording {
+    fn frame(&self) -> Frame {
+        self.frames[self.current].clone()
+    }
+
+    fn step(&mut self) -> bool {
+        if self.current + 1 < self.frames.len() {
+            self.current += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // counts down from a number
+    struct Countdown(u32);
+
+    impl Simulation for Countdown {
+        fn frame(&self) -> Frame {
+            Frame {
+                caption: format!("{} to go", self.0),
+                picture: "#".repeat(self.0 as usize) + "\n",
+            }
+        }
+
+        fn step(&mut self) -> bool {
+            if self.0 == 0 {
+                return false;
+            }
+            self.0 -= 1;
+            true
+        }
+    }
+
+    #[test]
+    fn replays_what_was_recorded() {
+        let mut out = vec![];
+        assert_eq!(record(&mut Countdown(3), &mut out).unwrap(), 4);
+
+        let mut recording = Recording::read(&out[..]).unwrap();
+        let mut countdown = Countdown(3);
+        assert_eq!(recording.len(), 4);
+        loop {
+            assert_eq!(recording.frame(), countdown.frame());
+            let more = recording.step();
+            assert_eq!(more, countdown.step());
+            if !more {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn empty_recordings_are_rejected() {
+        assert!(Recording::read(&b"\n"[..]).is_err());
+        assert!(Recording::read(&b"not json\n"[..]).is_err());
+    }
+}